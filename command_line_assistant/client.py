@@ -2,6 +2,7 @@
 
 import logging
 import os
+import signal
 import sys
 from argparse import ArgumentParser, Namespace
 
@@ -18,9 +19,14 @@ from command_line_assistant.commands.feedback import feedback_command
 from command_line_assistant.commands.history import history_command
 from command_line_assistant.commands.shell import shell_command
 from command_line_assistant.logger import setup_client_logging
+from command_line_assistant.rendering.colors import should_colorize
 from command_line_assistant.rendering.renderers import Renderer
 from command_line_assistant.rendering.theme import Theme
 
+#: Exit code used when the user cancels the execution with Ctrl + C. Follows
+#: the shell convention of 128 + the signal number (SIGINT).
+EX_INTERRUPTED: int = 128 + signal.SIGINT
+
 
 def register_subcommands() -> ArgumentParser:
     """Register all the subcommands for the CLI
@@ -54,6 +60,12 @@ def main() -> int:
     # specified the --plain flag. This allows the exceptions below that use
     # these renders to follow the user's preference.
     plain_in_argv = "-p" in sys.argv or "--plain" in sys.argv
+
+    # Colors are disabled for every command and message through the NO_COLOR
+    # environment variable, which is respected by the rendering functions.
+    if not should_colorize(sys.stdout, "--no-color" in sys.argv):
+        os.environ["NO_COLOR"] = "1"
+
     renderer = Renderer(plain=plain_in_argv, theme=Theme())
 
     try:
@@ -79,16 +91,18 @@ def main() -> int:
             parser.print_help()
             return os.EX_USAGE
 
-        # In case the uder specify the --debug, we will enable the logging here.
-        if parsed_args.debug:
-            setup_client_logging()
+        # In case the uder specify the --debug or --log-level, we will enable
+        # the logging here. An explicit level wins over --debug.
+        log_level = parsed_args.log_level or ("DEBUG" if parsed_args.debug else None)
+        if log_level:
+            setup_client_logging(log_level)
 
         return parsed_args.func(parsed_args)
 
     except ValueError as e:
         renderer.error(str(e))
         return os.EX_DATAERR
-    except DBusError as e:
+    except (DBusError, TimeoutError) as e:
         renderer.error(str(e))
         return os.EX_UNAVAILABLE
     except RuntimeError as e:
@@ -99,8 +113,8 @@ def main() -> int:
         )
         return os.EX_SOFTWARE
     except KeyboardInterrupt:
-        renderer.error("Keyboard interrupt detected. Exiting...")
-        return os.EX_OK
+        renderer.error("Cancelled.")
+        return EX_INTERRUPTED
 
 
 if __name__ == "__main__":
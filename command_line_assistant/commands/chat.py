@@ -1,14 +1,19 @@
 """Simplified chat command implementation."""
 
 import argparse
+import json
 import logging
 import os
 import platform
+import sys
 from argparse import Namespace
 from dataclasses import dataclass
 from io import TextIOWrapper
 from typing import Optional
 
+from dasbus.error import DBusError
+from gi.repository import Gio, GLib
+
 from command_line_assistant.commands.cli import CommandContext, argument, command
 from command_line_assistant.dbus.client import DbusClient
 from command_line_assistant.dbus.exceptions import (
@@ -24,8 +29,9 @@ from command_line_assistant.dbus.structures.chat import (
     SystemInfo,
     TerminalInput,
 )
-from command_line_assistant.exceptions import ChatCommandException
+from command_line_assistant.exceptions import ChatCommandException, ChatUsageException
 from command_line_assistant.rendering.animation import Spinner
+from command_line_assistant.rendering.markdown import extract_last_code_block
 from command_line_assistant.rendering.renderers import (
     Renderer,
     format_datetime,
@@ -38,6 +44,7 @@ from command_line_assistant.terminal.parser import (
 )
 from command_line_assistant.terminal.reader import TERMINAL_CAPTURE_FILE
 from command_line_assistant.utils.benchmark import TimingLogger
+from command_line_assistant.utils.clipboard import read_clipboard, write_clipboard
 from command_line_assistant.utils.environment import get_xdg_state_path
 from command_line_assistant.utils.files import (
     NamedFileLock,
@@ -73,6 +80,14 @@ ALWAYS_LEGAL_MESSAGE = "Always review AI-generated content prior to use."
 DEFAULT_CHAT_DESCRIPTION = "Default Command Line Assistant Chat."
 #: Default chat name when none is given
 DEFAULT_CHAT_NAME = "default"
+#: Prompt shown while waiting for a new question in the interactive mode
+INTERACTIVE_PROMPT = ">>> "
+#: Prompt shown while waiting for the next line of a multiline question
+INTERACTIVE_CONTINUATION_PROMPT = "... "
+#: Command to leave the interactive mode
+INTERACTIVE_EXIT_COMMAND = ".exit"
+#: Command to submit a multiline question right away
+INTERACTIVE_SEND_COMMAND = ".send"
 
 
 @dataclass
@@ -174,6 +189,16 @@ class InputSource:
     type=argparse.FileType("r"),
     help="File attachment to be read and sent alongside the query",
 )
+@argument(
+    "--from-clipboard",
+    action="store_true",
+    help="Use the contents of the clipboard as the question.",
+)
+@argument(
+    "--copy",
+    action="store_true",
+    help="Copy the last code block of the answer to the clipboard.",
+)
 @argument(
     "-i", "--interactive", action="store_true", help="Start an interactive chat session"
 )
@@ -195,6 +220,31 @@ class InputSource:
 @argument("--delete-all", action="store_true", help="Delete all chats")
 @argument("-n", "--name", nargs="?", help="Give a name to the chat session.")
 @argument("--description", nargs="?", help="Give a description to the chat session.")
+@argument(
+    "--timeout",
+    type=int,
+    help="Maximum amount of seconds to wait for an answer. By default, there is no limit.",
+)
+@argument(
+    "--no-progress",
+    action="store_true",
+    help="Don't show the progress indicator while waiting for an answer.",
+)
+@argument(
+    "--dry-run",
+    action="store_true",
+    help="Print the question that would be sent, with all of its context, without sending it.",
+)
+@argument(
+    "--output",
+    choices=["text", "json"],
+    default="text",
+    help=(
+        "The format of the answer for a single question. With json, the "
+        "question and the answer are printed as a JSON object and any other "
+        "message goes to the standard error."
+    ),
+)
 def chat_command(args: Namespace, context: CommandContext) -> int:
     """Main chat command implementation.
 
@@ -209,12 +259,25 @@ def chat_command(args: Namespace, context: CommandContext) -> int:
     Returns:
         int: The exit code.
     """
-    render = Renderer(args.plain, theme=Theme())
+    json_output = args.output == "json"
+    # Keep the standard output exclusive for the JSON object, so it can be
+    # parsed by other programs.
+    render = Renderer(
+        args.plain or json_output,
+        theme=Theme(),
+        stream=sys.stderr if json_output else None,
+        quiet=args.quiet,
+    )
     dbus = DbusClient()
 
-    user_id = dbus.user_proxy.GetUserId(context.effective_user_id)
-
     try:
+        # The interactive mode renders the answers for humans only, so there is
+        # no JSON object for the scripts to parse.
+        if json_output and args.interactive:
+            raise ChatUsageException(
+                "The option '--output json' can't be used together with '--interactive'."
+            )
+
         # Handle special arguments preprocessing
         if args.with_output:
             logger.debug(
@@ -223,6 +286,16 @@ def chat_command(args: Namespace, context: CommandContext) -> int:
             logger.debug("Original index is %s", args.with_output)
             args.with_output = -abs(args.with_output)
 
+        # The dry run never talks to clad, so it needs to be handled before
+        # any call to the proxies, as the daemon may not be running at all.
+        if args.dry_run and not (
+            args.list or args.delete or args.delete_all or args.interactive
+        ):
+            _display_dry_run(_compose_question(render, context, args))
+            return 0
+
+        user_id = dbus.user_proxy.GetUserId(context.effective_user_id)
+
         # Set default name and description
         name = args.name or DEFAULT_CHAT_NAME
         description = args.description or DEFAULT_CHAT_DESCRIPTION
@@ -258,8 +331,22 @@ def chat_command(args: Namespace, context: CommandContext) -> int:
             )
     except ChatCommandException as e:
         logger.info("Failed to execute chat command: %s", str(e))
+        if json_output:
+            return _display_json_error(str(e), e.code)
         render.error(str(e))
         return e.code
+    # The errors below are rendered by the client, unless the output must be
+    # a JSON object. The exit codes are the same as the client ones.
+    except ValueError as e:
+        if not json_output:
+            raise
+        logger.info("Failed to execute chat command: %s", str(e))
+        return _display_json_error(str(e), os.EX_DATAERR)
+    except (DBusError, TimeoutError) as e:
+        if not json_output:
+            raise
+        logger.info("Failed to execute chat command: %s", str(e))
+        return _display_json_error(str(e), os.EX_UNAVAILABLE)
 
 
 def _read_last_terminal_output(index: int) -> str:
@@ -376,6 +463,95 @@ def _display_response(renderer: Renderer, response: str) -> None:
     renderer.notice(ALWAYS_LEGAL_MESSAGE)
 
 
+def _display_json_response(renderer: Renderer, question: str, response: str) -> None:
+    """Display the question and its response as a JSON object.
+
+    Notes:
+        The legal messages are still rendered by the renderer, which is
+        expected to write to the standard error in this case.
+
+    Args:
+        renderer (Renderer): The renderer to use for the legal messages.
+        question (str): The question that was submitted.
+        response (str): The response to display.
+    """
+    if _handle_legal_message():
+        renderer.notice(LEGAL_NOTICE)
+
+    print(json.dumps({"query": question, "response": response, "exit_code": 0}))
+    renderer.notice(ALWAYS_LEGAL_MESSAGE)
+
+
+def _display_json_error(message: str, exit_code: int) -> int:
+    """Display an error as a JSON object.
+
+    Args:
+        message (str): The error message.
+        exit_code (int): The exit code of the command.
+
+    Returns:
+        int: The exit code of the command.
+    """
+    print(json.dumps({"error": message, "exit_code": exit_code}))
+    return exit_code
+
+
+def _display_dry_run(message_input: Question) -> None:
+    """Display the question that would be sent to clad as a JSON object.
+
+    Notes:
+        clad may still leave the system information out of the request to
+        the backend, depending on its privacy settings.
+
+    Args:
+        message_input (Question): The composed question.
+    """
+    print(
+        json.dumps(
+            {
+                "message": message_input.message,
+                "stdin": message_input.stdin.stdin,
+                "attachment": {
+                    "contents": message_input.attachment.contents,
+                    "mimetype": message_input.attachment.mimetype,
+                },
+                "terminal": message_input.terminal.output,
+                "systeminfo": {
+                    "os": message_input.systeminfo.os,
+                    "version": message_input.systeminfo.version,
+                    "arch": message_input.systeminfo.arch,
+                    "id": message_input.systeminfo.id,
+                },
+            },
+            indent=2,
+        )
+    )
+
+
+def _copy_code_block(renderer: Renderer, response: str) -> None:
+    """Copy the last code block of the response to the clipboard.
+
+    Notes:
+        Failing to copy is not fatal, as the response was already displayed.
+
+    Args:
+        renderer (Renderer): The renderer to use.
+        response (str): The response to search for code blocks.
+    """
+    code = extract_last_code_block(response)
+    if code is None:
+        renderer.warning("The answer has no code block to copy.")
+        return
+
+    try:
+        write_clipboard(code)
+    except ValueError as e:
+        renderer.warning(str(e))
+        return
+
+    renderer.info(f"Copied to the clipboard:\n{code}")
+
+
 @timing.timeit
 def _submit_question(
     dbus: DbusClient,
@@ -383,6 +559,8 @@ def _submit_question(
     chat_id: str,
     message_input: Question,
     plain: bool,
+    timeout: Optional[int] = None,
+    progress: bool = True,
 ) -> str:
     """Submit the question over dbus.
 
@@ -392,13 +570,15 @@ def _submit_question(
         chat_id (str): The chat id.
         message_input (Question): The question.
         plain (bool): Whether to render in plain text.
+        timeout (Optional[int]): Maximum amount of seconds to wait for the response.
+        progress (bool): Whether to show the progress indicator while waiting.
 
     Returns:
         str: The response.
     """
-    spinner = Spinner(message="Asking RHEL Lightspeed", plain=plain)
+    spinner = Spinner(message="Asking RHEL Lightspeed", plain=plain, enabled=progress)
     with spinner:
-        response = _get_response(dbus, message_input, user_id)
+        response = _get_response(dbus, message_input, user_id, timeout)
 
     try:
         dbus.history_proxy.WriteHistory(
@@ -493,6 +673,13 @@ def _gather_input_sources(args: Namespace) -> InputSource:
     attachment_mimetype = guess_mimetype(args.attachment)
     stdin = args.stdin.strip() if args.stdin else ""
     question = args.query_string.strip() if args.query_string else ""
+
+    if args.from_clipboard:
+        logger.debug("Reading the question from the clipboard.")
+        question = read_clipboard().strip()
+        if not question:
+            raise ValueError("The clipboard is empty. Copy your question first.")
+
     return InputSource(
         question, stdin, attachment, attachment_mimetype, terminal_output
     )
@@ -503,6 +690,7 @@ def _get_response(
     dbus: DbusClient,
     message_input: Question,
     user_id: str,
+    timeout: Optional[int] = None,
 ) -> str:
     """Get the response from the chat session.
 
@@ -510,11 +698,31 @@ def _get_response(
         dbus (DbusUtils): The DbusUtils instance.
         message_input (Question): The message input.
         user_id (str): The user ID.
+        timeout (Optional[int]): Maximum amount of seconds to wait for the
+            response. If None, the default timeout of the bus is used.
+
+    Raises:
+        TimeoutError: If the response didn't arrive within the given timeout.
 
     Returns:
         str: The response message.
     """
-    response = dbus.chat_proxy.AskQuestion(user_id, message_input.structure())
+    if not timeout:
+        response = dbus.chat_proxy.AskQuestion(user_id, message_input.structure())
+        return Response.from_structure(response).message
+
+    try:
+        # The timeout for dbus calls is given in milliseconds.
+        response = dbus.chat_proxy.AskQuestion(
+            user_id, message_input.structure(), timeout=timeout * 1000
+        )
+    except GLib.Error as e:
+        if e.matches(Gio.io_error_quark(), Gio.IOErrorEnum.TIMED_OUT):
+            raise TimeoutError(
+                f"Didn't receive an answer within {timeout} seconds. Please try again or increase the --timeout value."
+            ) from e
+        raise
+
     return Response.from_structure(response).message
 
 
@@ -587,6 +795,40 @@ def _delete_all_chats(render: Renderer, dbus: DbusClient, user_id: str) -> int:
         ) from e
 
 
+def _read_interactive_question() -> Optional[str]:
+    """Read a question in the interactive mode.
+
+    Lines ending with a backslash are continued in the next one, until a line
+    without it or the send command is typed.
+
+    Returns:
+        Optional[str]: The question, or None if the user wants to leave the
+        interactive mode.
+    """
+    lines: list[str] = []
+    while True:
+        prompt = INTERACTIVE_CONTINUATION_PROMPT if lines else INTERACTIVE_PROMPT
+        try:
+            line = input(prompt).rstrip()
+        except EOFError:
+            # Handle Ctrl+D
+            return None
+
+        if line.strip() == INTERACTIVE_EXIT_COMMAND:
+            return None
+
+        if line.strip() == INTERACTIVE_SEND_COMMAND:
+            break
+
+        if not line.endswith("\\"):
+            lines.append(line)
+            break
+
+        lines.append(line[:-1])
+
+    return "\n".join(lines).strip()
+
+
 def _interactive_chat(
     render: Renderer,
     dbus: DbusClient,
@@ -622,20 +864,16 @@ def _interactive_chat(
     chat_id = _create_chat_session(dbus, user_id, name, description)
 
     # Display banner message
-    render.normal(
-        "Welcome to the interactive mode for command line assistant! To exit, press Ctrl + C or type '.exit'.\nThe current session does not include running context."
+    render.status(
+        f"Welcome to the interactive mode for command line assistant! To exit, press Ctrl + C or type '{INTERACTIVE_EXIT_COMMAND}'.\n"
+        "End a line with '\\' to continue the question in the next line.\n"
+        "The current session does not include running context."
     )
 
     try:
         while True:
-            try:
-                question = input(">>> ").strip()
-            except EOFError:
-                # Handle Ctrl+D
-                break
-
-            # Handle exit command
-            if question == ".exit":
+            question = _read_interactive_question()
+            if question is None:
                 break
 
             if not question:
@@ -650,6 +888,8 @@ def _interactive_chat(
                 chat_id=chat_id,
                 message_input=message_input,
                 plain=args.plain,
+                timeout=args.timeout,
+                progress=not (args.no_progress or args.quiet),
             )
             _display_response(render, response)
     except KeyboardInterrupt:
@@ -683,16 +923,7 @@ def _single_question(
     Returns:
         int: The exit code.
     """
-    # Validate query
-    is_query_invalid = _validate_query_composition(args)
-
-    # In case we have any string in it, it means the query is invalid and we
-    # raise an exception with the message returned.
-    if is_query_invalid:
-        raise ChatCommandException(is_query_invalid)
-
-    input_source = _gather_input_sources(args)
-    message_input = _compose_message_input(render, context, input_source)
+    message_input = _compose_question(render, context, args)
 
     try:
         chat_id = _create_chat_session(dbus, user_id, name, description)
@@ -702,15 +933,51 @@ def _single_question(
             chat_id=chat_id,
             message_input=message_input,
             plain=args.plain,
+            timeout=args.timeout,
+            progress=not (args.no_progress or args.quiet),
         )
 
-        _display_response(render, response)
+        if args.output == "json":
+            _display_json_response(render, message_input.message, response)
+        else:
+            _display_response(render, response)
+
+        if args.copy:
+            _copy_code_block(render, response)
         return 0
     except ValueError as e:
         message = f"Failed to get a response from LLM. {str(e)}"
         raise ChatCommandException(message) from e
 
 
+def _compose_question(
+    render: Renderer, context: CommandContext, args: Namespace
+) -> Question:
+    """Validate the query and compose the question from all of its sources.
+
+    Args:
+        render (RenderUtils): The RenderUtils instance.
+        context (CommandContext): The CommandContext instance.
+        args (Namespace): The Namespace instance.
+
+    Raises:
+        ChatUsageException: In case the query is not valid.
+
+    Returns:
+        Question: The composed question.
+    """
+    # Validate query
+    is_query_invalid = _validate_query_composition(args)
+
+    # In case we have any string in it, it means the query is invalid and we
+    # raise an exception with the message returned.
+    if is_query_invalid:
+        raise ChatUsageException(is_query_invalid)
+
+    input_source = _gather_input_sources(args)
+    return _compose_message_input(render, context, input_source)
+
+
 def _validate_query_composition(args: Namespace) -> Optional[str]:
     """Valid if the query compostion is valid or not.
 
@@ -734,6 +1001,12 @@ def _validate_query_composition(args: Namespace) -> Optional[str]:
         )
         return "Your stdin input needs to have at least 2 characters."
 
+    if args.from_clipboard and args.query_string:
+        return "The option '--from-clipboard' can't be used together with a query."
+
+    if args.timeout is not None and args.timeout <= 0:
+        return "The timeout needs to be a positive number of seconds."
+
     if args.with_output and not TERMINAL_CAPTURE_FILE.exists():
         return "Adding context from terminal output is only allowed if terminal capture is active."
 
@@ -40,6 +40,9 @@ SubParsersAction = _SubParsersAction
 GLOBAL_FLAGS: list[str] = [
     "-p",
     "--plain",
+    "--no-color",
+    "-q",
+    "--quiet",
     "--debug",
     "--version",
     "-v",
@@ -48,6 +51,15 @@ GLOBAL_FLAGS: list[str] = [
 ]
 ARGS_WITH_VALUES: list[str] = ["--clear"]
 
+#: Global flags that take a value, as in `--log-level INFO`.
+GLOBAL_ARGS_WITH_VALUES: list[str] = ["--log-level"]
+
+#: Levels accepted by the --log-level flag.
+LOG_LEVELS: tuple[str, ...] = ("CRITICAL", "ERROR", "WARNING", "INFO", "DEBUG")
+
+#: Everything after this sentinel is always considered a question for chat.
+FORCE_CHAT_SENTINEL: str = "--"
+
 OS_RELEASE_PATH = Path("/etc/os-release")
 
 # Define a `CommandFunc` type alias to assist in the type definitions for the
@@ -129,19 +141,73 @@ def add_default_command(stdin: Optional[str], argv: list[str]) -> list[str]:
     if not argv_list and not stdin:
         return argv_list
 
+    # The sentinel only forces the chat when no other subcommand was used
+    # before it. Otherwise, it is left for argparse to handle, as usual.
+    if FORCE_CHAT_SENTINEL in argv_list and _subcommand_used(
+        argv_list[: argv_list.index(FORCE_CHAT_SENTINEL)]
+    ) in (None, "chat"):
+        return _force_chat_command(argv_list)
+
+    global_flags, command_args = _split_global_flags(argv_list)
+
+    subcommand = _subcommand_used(argv)
+    if not subcommand:
+        return global_flags + ["chat"] + command_args
+
+    return argv_list
+
+
+def _split_global_flags(argv_list: list[str]) -> tuple[list[str], list[str]]:
+    """Separate the global flags, and their values, from the other arguments.
+
+    Arguments:
+        argv_list (list[str]): List of arguments from CLI without the program name
+
+    Returns:
+        tuple[list[str], list[str]]: The global flags and the other arguments.
+    """
     global_flags = []
     command_args = []
+    expects_value = False
     for arg in argv_list:
-        if arg in GLOBAL_FLAGS:
+        if expects_value or arg in GLOBAL_FLAGS:
+            global_flags.append(arg)
+            expects_value = False
+        elif arg in GLOBAL_ARGS_WITH_VALUES:
+            global_flags.append(arg)
+            expects_value = True
+        elif arg.split("=", 1)[0] in GLOBAL_ARGS_WITH_VALUES:
             global_flags.append(arg)
         else:
             command_args.append(arg)
 
-    subcommand = _subcommand_used(argv)
-    if not subcommand:
-        return global_flags + ["chat"] + command_args
+    return global_flags, command_args
 
-    return argv_list
+
+def _force_chat_command(argv_list: list[str]) -> list[str]:
+    """Route everything after the sentinel to the chat command.
+
+    This bypasses the subcommand detection, so questions that start with a
+    subcommand name, like `c -- shell is broken`, still reach the chat.
+
+    Arguments:
+        argv_list (list[str]): List of arguments from CLI without the program name
+
+    Returns:
+        list[str]: The arguments with the chat command and the question.
+    """
+    index = argv_list.index(FORCE_CHAT_SENTINEL)
+    before, after = argv_list[:index], argv_list[index + 1 :]
+
+    # In case `chat` was explicitly used, we only need to join the question.
+    if _subcommand_used(before) != "chat":
+        global_flags, command_args = _split_global_flags(before)
+        before = global_flags + ["chat"] + command_args
+
+    if not after:
+        return before
+
+    return before + [FORCE_CHAT_SENTINEL, " ".join(after)]
 
 
 def _subcommand_used(args: list[str]) -> Optional[str]:
@@ -163,7 +229,9 @@ def _subcommand_used(args: list[str]) -> Optional[str]:
             return argument
 
         # Otherwise, check if this is the second part of an arg that takes a value.
-        elif index > 0 and args[index - 1] in ARGS_WITH_VALUES:
+        elif (
+            index > 0 and args[index - 1] in ARGS_WITH_VALUES + GLOBAL_ARGS_WITH_VALUES
+        ):
             continue
 
     return None
@@ -188,6 +256,12 @@ def create_argument_parser() -> tuple[ArgumentParser, SubParsersAction]:
     parser.add_argument(
         "--debug", action="store_true", help="Enable debug logging information"
     )
+    parser.add_argument(
+        "--log-level",
+        type=str.upper,
+        choices=LOG_LEVELS,
+        help="Enable logging information from the given level. --debug is the same as --log-level DEBUG.",
+    )
     parser.add_argument(
         "-h",
         "--help",
@@ -210,6 +284,19 @@ def create_argument_parser() -> tuple[ArgumentParser, SubParsersAction]:
         help="Enable plain output. This will disable colors, animations, and other rich content.",
         default=False,
     )
+    parser.add_argument(
+        "--no-color",
+        action="store_true",
+        help="Disable colors in the output. Also respects the NO_COLOR environment variable.",
+        default=False,
+    )
+    parser.add_argument(
+        "-q",
+        "--quiet",
+        action="store_true",
+        help="Suppress the status messages, keeping only the results and errors.",
+        default=False,
+    )
     commands_parser = parser.add_subparsers(dest="command")
     return parser, commands_parser
 
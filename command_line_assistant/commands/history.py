@@ -2,6 +2,7 @@
 
 import logging
 from argparse import Namespace
+from typing import Any
 
 from command_line_assistant.commands.cli import (
     CommandContext,
@@ -85,6 +86,11 @@ def _show_history(renderer: Renderer, entries: HistoryList) -> None:
     help="Clear the entire history for a given chat. Use --from-chat with its given name to clear that particular history.",
 )
 @argument("--clear-all", action="store_true", help="Clear the entire history.")
+@argument(
+    "--stats",
+    action="store_true",
+    help="Show a summary of the conversation history, like the number of interactions.",
+)
 def history_command(args: Namespace, context: CommandContext) -> int:
     """History command implementation.
 
@@ -96,11 +102,15 @@ def history_command(args: Namespace, context: CommandContext) -> int:
         int: Exit code.
     """
     dbus = DbusClient()
-    render = Renderer(args.plain, theme=Theme())
+    render = Renderer(args.plain, theme=Theme(), quiet=args.quiet)
 
     user_id = dbus.user_proxy.GetUserId(context.effective_user_id)
 
     try:
+        # The summary covers every chat, so it doesn't depend on --from-chat.
+        if args.stats:
+            return _history_stats(render, dbus, user_id)
+
         is_chat_available = dbus.chat_proxy.IsChatAvailable(user_id, args.from_chat)
 
         if not is_chat_available:
@@ -200,7 +210,7 @@ def _first_history(
         int: The exit code.
     """
     try:
-        render.normal("Getting first conversation from history.")
+        render.status("Getting first conversation from history.")
         response = dbus.history_proxy.GetFirstConversation(user_id, from_chat)
         history = HistoryList.from_structure(response)
         _show_history(render, history)
@@ -226,7 +236,7 @@ def _last_history(
         int: The exit code.
     """
     try:
-        render.normal("Getting last conversation from history.")
+        render.status("Getting last conversation from history.")
         response = dbus.history_proxy.GetLastConversation(user_id, from_chat)
         history = HistoryList.from_structure(response)
         _show_history(render, history)
@@ -258,7 +268,7 @@ def _filter_history(
         int: The exit code.
     """
     try:
-        render.normal("Filtering conversation history.")
+        render.status("Filtering conversation history.")
         response = dbus.history_proxy.GetFilteredConversation(
             user_id, filter_text, from_chat
         )
@@ -287,7 +297,7 @@ def _all_history(render: Renderer, dbus: DbusClient, user_id: str, plain: bool)
         int: The exit code.
     """
     try:
-        render.normal("Getting all conversations from history.")
+        render.status("Getting all conversations from history.")
         response = dbus.history_proxy.GetHistory(user_id)
         history = HistoryList.from_structure(response)
         _show_history(render, history)
@@ -295,3 +305,72 @@ def _all_history(render: Renderer, dbus: DbusClient, user_id: str, plain: bool)
     except (HistoryNotAvailableError, HistoryNotEnabledError) as e:
         logger.debug("Failed to retrieve the all history entries: %s", str(e))
         raise HistoryCommandException(str(e)) from e
+
+
+def _compute_history_stats(entries: HistoryList) -> dict[str, Any]:
+    """Compute the summary of the history entries in a single pass.
+
+    Args:
+        entries (HistoryList): The list of history entries.
+
+    Returns:
+        dict[str, Any]: The number of interactions and chats, the dates of the
+        first and last entries and the average response length.
+    """
+    chats = set()
+    first_entry = ""
+    last_entry = ""
+    total_response_length = 0
+
+    for entry in entries.histories:
+        chats.add(entry.chat_name)
+        total_response_length += len(entry.response)
+        if not first_entry or entry.created_at < first_entry:
+            first_entry = entry.created_at
+        if not last_entry or entry.created_at > last_entry:
+            last_entry = entry.created_at
+
+    interactions = len(entries.histories)
+    return {
+        "interactions": interactions,
+        "chats": len(chats),
+        "first_entry": first_entry,
+        "last_entry": last_entry,
+        "average_response_length": (
+            round(total_response_length / interactions) if interactions else 0
+        ),
+    }
+
+
+def _history_stats(render: Renderer, dbus: DbusClient, user_id: str) -> int:
+    """Show a summary of the conversation history.
+
+    Args:
+        render (RenderUtils): The render utils.
+        dbus (DbusUtils): The dbus utils.
+        user_id (str): The user id.
+
+    Returns:
+        int: The exit code.
+    """
+    try:
+        history = HistoryList.from_structure(dbus.history_proxy.GetHistory(user_id))
+    except HistoryNotAvailableError:
+        # An empty history is a valid summary, with everything set to zero.
+        history = HistoryList()
+    except HistoryNotEnabledError as e:
+        logger.debug("Failed to retrieve the history summary: %s", str(e))
+        raise HistoryCommandException(str(e)) from e
+
+    stats = _compute_history_stats(history)
+    first_entry = format_datetime(stats["first_entry"]) if stats["first_entry"] else "-"
+    last_entry = format_datetime(stats["last_entry"]) if stats["last_entry"] else "-"
+
+    render.normal(f"Interactions: {stats['interactions']}")
+    render.normal(f"Chats: {stats['chats']}")
+    render.normal(f"First entry: {first_entry}")
+    render.normal(f"Last entry: {last_entry}")
+    render.normal(
+        f"Average response length: {stats['average_response_length']} characters"
+    )
+    return 0
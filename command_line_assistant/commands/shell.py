@@ -1,9 +1,14 @@
 """Simplified shell command implementation."""
 
 import logging
-from argparse import Namespace
+import os
+import shutil
+import tempfile
+from argparse import ArgumentParser, Namespace
+from collections.abc import Callable
+from enum import Enum
 from pathlib import Path
-from typing import Union
+from typing import Optional, Union
 
 from command_line_assistant.commands.cli import (
     CommandContext,
@@ -11,7 +16,14 @@ from command_line_assistant.commands.cli import (
     command,
 )
 from command_line_assistant.exceptions import ShellCommandException
-from command_line_assistant.integrations import BASH_INTERACTIVE
+from command_line_assistant.integrations import (
+    generate_alias,
+    generate_bash_completion,
+    generate_bash_interactive,
+    generate_fish_completion,
+    generate_zsh_completion,
+    get_c_binary,
+)
 from command_line_assistant.rendering.renderers import Renderer
 from command_line_assistant.rendering.theme import Theme
 from command_line_assistant.terminal.reader import (
@@ -29,6 +41,33 @@ PERSISTENT_TERMINAL_CAPTURE_FILE: Path = Path(
     BASH_RC_D_PATH, "cla-persistent-capture.bashrc"
 )
 ESSENTIAL_EXPORTS_FILE: Path = Path(BASH_RC_D_PATH, "cla-exports.bashrc")
+BASH_COMPLETION_FILE: Path = Path(
+    "~/.local/share/bash-completion/completions/c"
+).expanduser()
+ZSH_COMPLETION_FILE: Path = Path("~/.local/share/zsh/site-functions/_c").expanduser()
+FISH_COMPLETION_FILE: Path = Path("~/.config/fish/completions/c.fish").expanduser()
+BASH_RC_FILE: Path = Path("~/.bashrc").expanduser()
+ZSH_RC_FILE: Path = Path(os.environ.get("ZDOTDIR", "~"), ".zshrc").expanduser()
+FISH_RC_FILE: Path = Path("~/.config/fish/config.fish").expanduser()
+ALIAS_BLOCK_START: str = "# >>> command-line-assistant alias >>>"
+ALIAS_BLOCK_END: str = "# <<< command-line-assistant alias <<<"
+
+
+class ShellType(Enum):
+    """The shells that we support for the integrations."""
+
+    BASH = "bash"
+    ZSH = "zsh"
+    FISH = "fish"
+
+    def __str__(self) -> str:
+        """Use the value as the string representation, so argparse can show
+        the choices in the help message."""
+        return self.value
+
+
+#: The names of the shells that we support for the integrations.
+SUPPORTED_SHELLS: tuple[str, ...] = tuple(shell.value for shell in ShellType)
 
 
 @command("shell", help="Manage shell integrations")
@@ -51,6 +90,69 @@ ESSENTIAL_EXPORTS_FILE: Path = Path(BASH_RC_D_PATH, "cla-exports.bashrc")
     action="store_true",
     help="Disable the shell integration for interactive mode on the system.",
 )
+@argument(
+    "--enable-completion",
+    action="store_true",
+    help=(
+        "Install the completion script for the c command. The shell is "
+        "detected automatically if --shell-type is not given."
+    ),
+)
+@argument(
+    "--disable-completion",
+    action="store_true",
+    help=(
+        "Remove the completion script for the c command. If --shell-type is "
+        "not given, the completion is removed for all supported shells."
+    ),
+)
+@argument(
+    "--print-completion",
+    action="store_true",
+    help=(
+        "Print the completion script for the c command instead of installing "
+        "it. The shell is detected automatically if --shell-type is not given."
+    ),
+)
+@argument(
+    "--print-all-completions",
+    action="store_true",
+    help=(
+        "Print the completion scripts for all supported shells, each one "
+        "preceded by a header with the shell name and its install location."
+    ),
+)
+@argument(
+    "--install-alias",
+    action="store_true",
+    help=(
+        "Add a '??' alias to the rc file of your shell, so 'c chat' can be "
+        "called as '?? question'. The shell is detected automatically if "
+        "--shell-type is not given."
+    ),
+)
+@argument(
+    "--uninstall-alias",
+    action="store_true",
+    help=(
+        "Remove the '??' alias from the rc file of your shell. The shell is "
+        "detected automatically if --shell-type is not given."
+    ),
+)
+@argument(
+    "--status",
+    action="store_true",
+    help=(
+        "Show whether the completion for the c command is installed. If "
+        "--shell-type is not given, all supported shells are checked."
+    ),
+)
+@argument(
+    "--shell-type",
+    type=ShellType,
+    choices=list(ShellType),
+    help="The shell to manage the integration for.",
+)
 def shell_command(args: Namespace, context: CommandContext) -> int:
     """Shell command implementation.
 
@@ -61,18 +163,34 @@ def shell_command(args: Namespace, context: CommandContext) -> int:
     Returns:
         int: Exit code
     """
-    render = Renderer(args.plain, theme=Theme())
+    render = Renderer(args.plain, theme=Theme(), quiet=args.quiet)
 
     try:
         # Handle different operations
         if args.enable_interactive:
             return _write_bash_functions(
-                render, INTERACTIVE_MODE_INTEGRATION_FILE, BASH_INTERACTIVE
+                render,
+                INTERACTIVE_MODE_INTEGRATION_FILE,
+                generate_bash_interactive(get_c_binary()),
             )
         elif args.disable_interactive:
             return _remove_bash_functions(render, INTERACTIVE_MODE_INTEGRATION_FILE)
         elif args.enable_capture:
             return _enable_capture(render)
+        elif args.enable_completion:
+            return _install_completion(render, args.shell_type)
+        elif args.disable_completion:
+            return _remove_completion(render, args.shell_type)
+        elif args.print_completion:
+            return _print_completion(args.shell_type)
+        elif args.print_all_completions:
+            return _print_all_completions()
+        elif args.install_alias:
+            return _install_alias(render, args.shell_type)
+        elif args.uninstall_alias:
+            return _uninstall_alias(render, args.shell_type)
+        elif args.status:
+            return _completion_status(render, args.shell_type)
         else:
             # If no specific operation is provided, show help-like message
             render.warning(
@@ -182,11 +300,406 @@ def _enable_capture(render: Renderer) -> int:
         )
 
     with file_lock:
-        render.normal("Starting terminal reader. Press Ctrl + D to stop the capturing.")
-        render.normal(
+        render.status("Starting terminal reader. Press Ctrl + D to stop the capturing.")
+        render.status(
             f"Terminal capture log is being written to {TERMINAL_CAPTURE_FILE}"
         )
         create_folder(BASH_RC_D_PATH)
         start_capturing()
 
     return 0
+
+
+def _build_parser() -> ArgumentParser:
+    """Build the parser with all the commands registered.
+
+    Returns:
+        ArgumentParser: The same parser used by the c command
+    """
+    # Import it here, as the client module imports this module to register
+    # the commands.
+    from command_line_assistant.client import register_subcommands
+
+    return register_subcommands()
+
+
+def _detect_shell() -> Optional[ShellType]:
+    """Detect the shell of the current user.
+
+    The shell is detected from the `SHELL` environment variable, falling back
+    to the name of the parent process, which usually is the shell that invoked
+    the command.
+
+    Returns:
+        Optional[ShellType]: The detected shell if it is supported, otherwise None.
+    """
+    candidates = [os.environ.get("SHELL", "")]
+    try:
+        candidates.append(Path(f"/proc/{os.getppid()}/comm").read_text().strip())
+    except OSError as e:
+        logger.debug("Couldn't read the parent process name: %s", str(e))
+
+    for candidate in candidates:
+        # Login shells are usually prefixed with a dash, like "-bash".
+        name = Path(candidate).name.lstrip("-")
+        if name in SUPPORTED_SHELLS:
+            return ShellType(name)
+
+    return None
+
+
+def _resolve_shell_type(shell_type: Optional[ShellType]) -> ShellType:
+    """Resolve the shell to use, detecting it if it was not given.
+
+    Args:
+        shell_type (Optional[ShellType]): The shell given by the user
+
+    Raises:
+        ShellCommandException: If the shell was not given and can't be detected.
+
+    Returns:
+        ShellType: The shell to use
+    """
+    if shell_type:
+        return shell_type
+
+    detected_shell = _detect_shell()
+    if not detected_shell:
+        raise ShellCommandException(
+            "Couldn't detect your shell. Please specify it with --shell-type. "
+            f"Supported shells are: {', '.join(SUPPORTED_SHELLS)}."
+        )
+
+    logger.debug("Detected shell '%s'", detected_shell.value)
+    return detected_shell
+
+
+def _get_completion_target(
+    shell_type: ShellType,
+) -> tuple[Path, Callable[[ArgumentParser], str]]:
+    """Retrieve the completion file and the script generator for a shell.
+
+    Args:
+        shell_type (ShellType): The shell to retrieve the completion target for
+
+    Returns:
+        tuple[Path, Callable[[ArgumentParser], str]]: The path of the
+        completion file and the function that generates its contents
+    """
+    targets = {
+        ShellType.BASH: (BASH_COMPLETION_FILE, generate_bash_completion),
+        ShellType.ZSH: (ZSH_COMPLETION_FILE, generate_zsh_completion),
+        ShellType.FISH: (FISH_COMPLETION_FILE, generate_fish_completion),
+    }
+    return targets[shell_type]
+
+
+def _install_completion(render: Renderer, shell_type: Optional[ShellType]) -> int:
+    """Generate and install the completion script for the given shell.
+
+    Args:
+        render (RenderUtils): Command utilities instance
+        shell_type (Optional[ShellType]): The shell to install the completion for
+
+    Raises:
+        ShellCommandException: If the shell can't be detected or the completion
+            file can't be written.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    file, generate = _get_completion_target(_resolve_shell_type(shell_type))
+    contents = generate(_build_parser())
+    try:
+        create_folder(file.parent, parents=True)
+        write_file(contents, file, mode=0o644)
+    except PermissionError as e:
+        raise ShellCommandException(
+            f"Couldn't write the completion file at {file}: {e.strerror}"
+        ) from e
+
+    render.normal(
+        f"Completion successfully installed at {file}. "
+        "In order to use it, please restart your terminal."
+    )
+    return 0
+
+
+def _remove_completion(render: Renderer, shell_type: Optional[ShellType]) -> int:
+    """Remove the installed completion script for the given shell.
+
+    Args:
+        render (RenderUtils): Command utilities instance
+        shell_type (Optional[ShellType]): The shell to remove the completion for. If
+            not given, the completion is removed for all supported shells.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    shells = [shell_type] if shell_type else list(ShellType)
+
+    removed = False
+    for shell in shells:
+        file, _ = _get_completion_target(shell)
+        # Only regular files (or links to them) are removed, we never want to
+        # remove anything else that happens to be in the completion location.
+        if not file.is_file() and not file.is_symlink():
+            logger.debug("Couldn't find completion file at '%s'", str(file))
+            continue
+
+        file.unlink(missing_ok=True)
+        removed = True
+        render.normal(f"Removed {shell.value} completion from {file}.")
+
+    if not removed:
+        render.warning(
+            "It seems that the completion is not installed. Skipping operation."
+        )
+
+    return 0
+
+
+def _print_completion(shell_type: Optional[ShellType]) -> int:
+    """Print the completion script for the given shell to stdout.
+
+    Args:
+        shell_type (Optional[ShellType]): The shell to print the completion for
+
+    Raises:
+        ShellCommandException: If the shell can't be detected.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    _, generate = _get_completion_target(_resolve_shell_type(shell_type))
+    # Write the script as is, without any rendering, so it can be redirected
+    # to a file.
+    print(generate(_build_parser()), end="")
+    return 0
+
+
+def _print_all_completions() -> int:
+    """Print the completion scripts for all supported shells to stdout.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    parser = _build_parser()
+    for shell in ShellType:
+        file, generate = _get_completion_target(shell)
+        print(f"# ===== {shell.value}: {file} =====")
+        print(generate(parser))
+
+    return 0
+
+
+def _completion_status(render: Renderer, shell_type: Optional[ShellType]) -> int:
+    """Report whether the completion is installed for the given shell.
+
+    Args:
+        render (RenderUtils): Command utilities instance
+        shell_type (Optional[ShellType]): The shell to check. If not given, all
+            supported shells are checked.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    shells = [shell_type] if shell_type else list(ShellType)
+
+    installed = True
+    for shell in shells:
+        file, _ = _get_completion_target(shell)
+        if file.exists():
+            render.normal(f"{shell.value}: completion installed at {file}")
+        else:
+            installed = False
+            render.normal(
+                f"{shell.value}: completion not installed (expected at {file})"
+            )
+
+    # Only fail when the user asked about a specific shell.
+    if shell_type and not installed:
+        return 2
+
+    return 0
+
+
+def _get_rc_file(shell_type: ShellType) -> Path:
+    """Retrieve the rc file for the given shell.
+
+    Args:
+        shell_type (ShellType): The shell to retrieve the rc file for
+
+    Returns:
+        Path: The path of the rc file
+    """
+    rc_files = {
+        ShellType.BASH: BASH_RC_FILE,
+        ShellType.ZSH: ZSH_RC_FILE,
+        ShellType.FISH: FISH_RC_FILE,
+    }
+    return rc_files[shell_type]
+
+
+def _read_rc_file(file: Path) -> tuple[str, Optional[tuple[int, int]]]:
+    """Read the rc file and locate the alias block in it.
+
+    Args:
+        file (Path): The rc file to read
+
+    Raises:
+        ShellCommandException: If the rc file can't be read or the alias block
+            in it is malformed.
+
+    Returns:
+        tuple[str, Optional[tuple[int, int]]]: The contents of the rc file and
+        the start and end offsets of the alias block, if present
+    """
+    try:
+        contents = file.read_text(encoding="utf-8")
+    except FileNotFoundError as e:
+        raise ShellCommandException(
+            f"Couldn't find the rc file at {file}. Create it first or add the alias manually."
+        ) from e
+    except (OSError, UnicodeDecodeError) as e:
+        raise ShellCommandException(f"Couldn't read the rc file at {file}.") from e
+
+    start = contents.find(ALIAS_BLOCK_START)
+    end = contents.find(ALIAS_BLOCK_END)
+    if start == -1 and end == -1:
+        return contents, None
+
+    if start == -1 or end < start:
+        raise ShellCommandException(
+            f"The alias block in {file} is malformed. Please fix it manually."
+        )
+
+    end += len(ALIAS_BLOCK_END)
+    # Include the line break after the block, so removing it leaves no
+    # leftovers behind.
+    if contents[end : end + 1] == "\n":
+        end += 1
+
+    return contents, (start, end)
+
+
+def _write_rc_file(file: Path, contents: str) -> None:
+    """Replace the contents of the rc file.
+
+    Args:
+        file (Path): The rc file to write
+        contents (str): The new contents of the rc file
+
+    Raises:
+        ShellCommandException: If the rc file can't be written.
+    """
+    # Replace the file the symlinks point to, so they are kept in place.
+    target = file.resolve()
+    temporary_file = None
+    try:
+        # Write to a temporary file first and move it in place afterwards, so
+        # an interrupted write never leaves the rc file truncated.
+        with tempfile.NamedTemporaryFile(
+            mode="w",
+            encoding="utf-8",
+            dir=target.parent,
+            prefix=f".{target.name}.",
+            suffix=".tmp",
+            delete=False,
+        ) as handler:
+            temporary_file = Path(handler.name)
+            handler.write(contents)
+
+        shutil.copymode(target, temporary_file)
+        os.replace(temporary_file, target)
+        temporary_file = None
+    except OSError as e:
+        raise ShellCommandException(
+            f"Couldn't write the rc file at {file}: {e.strerror}"
+        ) from e
+    finally:
+        # The temporary file is only left behind if the write failed before it
+        # was moved in place.
+        if temporary_file:
+            temporary_file.unlink(missing_ok=True)
+
+
+def _install_alias(render: Renderer, shell_type: Optional[ShellType]) -> int:
+    """Add the alias block to the rc file of the given shell.
+
+    Installing it again replaces the existing block, so the rc file always has
+    a single and up to date alias.
+
+    Args:
+        render (RenderUtils): Command utilities instance
+        shell_type (Optional[ShellType]): The shell to install the alias for
+
+    Raises:
+        ShellCommandException: If the shell can't be detected or the rc file
+            can't be read or written.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    shell = _resolve_shell_type(shell_type)
+    file = _get_rc_file(shell)
+    contents, block = _read_rc_file(file)
+
+    alias = generate_alias(
+        fish=shell == ShellType.FISH,
+        c_binary=get_c_binary(),
+        zsh=shell == ShellType.ZSH,
+    )
+    new_block = f"{ALIAS_BLOCK_START}\n{alias}{ALIAS_BLOCK_END}\n"
+
+    if block:
+        start, end = block
+        if contents[start:end] == new_block:
+            render.normal(f"The alias is already installed in {file}.")
+            return 0
+        new_contents = contents[:start] + new_block + contents[end:]
+    else:
+        # Keep an empty line between the block and the rest of the rc file.
+        if contents and not contents.endswith("\n"):
+            contents += "\n"
+        if contents:
+            contents += "\n"
+        new_contents = contents + new_block
+
+    _write_rc_file(file, new_contents)
+    render.normal(
+        f"Alias successfully installed in {file}. "
+        "In order to use it, please restart your terminal."
+    )
+    return 0
+
+
+def _uninstall_alias(render: Renderer, shell_type: Optional[ShellType]) -> int:
+    """Remove the alias block from the rc file of the given shell.
+
+    Args:
+        render (RenderUtils): Command utilities instance
+        shell_type (Optional[ShellType]): The shell to remove the alias for
+
+    Raises:
+        ShellCommandException: If the shell can't be detected or the rc file
+            can't be read or written.
+
+    Returns:
+        int: The exit code of the operation
+    """
+    file = _get_rc_file(_resolve_shell_type(shell_type))
+    contents, block = _read_rc_file(file)
+
+    if not block:
+        render.warning("It seems that the alias is not installed. Skipping operation.")
+        return 0
+
+    start, end = block
+    # Drop the empty line added before the block on its installation.
+    if contents[:start].endswith("\n\n"):
+        start -= 1
+
+    _write_rc_file(file, contents[:start] + contents[end:])
+    render.normal(f"Removed the alias from {file}.")
+    return 0
@@ -4,13 +4,16 @@ from __future__ import annotations
 
 import dataclasses
 import logging
+import os
 import sys
 from pathlib import Path
+from typing import Optional, Union
 
 from command_line_assistant.config.schemas.backend import BackendSchema
 from command_line_assistant.config.schemas.database import DatabaseSchema
 from command_line_assistant.config.schemas.history import HistorySchema
 from command_line_assistant.config.schemas.logging import LoggingSchema
+from command_line_assistant.config.schemas.privacy import PrivacySchema
 from command_line_assistant.utils.environment import get_xdg_config_path
 
 # tomllib is available in the stdlib after Python3.11. Before that, we import
@@ -28,6 +31,9 @@ CONFIG_FILE_DEFINITION: tuple[str, str] = (
     "config.toml",
 )
 
+#: Environment variable to override the location of the config file.
+CONFIG_FILE_ENV: str = "CLAD_CONFIG"
+
 logger = logging.getLogger(__name__)
 
 
@@ -45,38 +51,92 @@ class Config:
         history (HistorySchema): Match the `py:HistorySchema` class and their fields
         backend (BackendSchema): Match the `py:BackendSchema` class and their fields
         logging (LoggingSchema): Match the `py:LoggingSchema` class and their fields
+        privacy (PrivacySchema): Match the `py:PrivacySchema` class and their fields
     """
 
     database: DatabaseSchema = dataclasses.field(default_factory=DatabaseSchema)
     history: HistorySchema = dataclasses.field(default_factory=HistorySchema)
     backend: BackendSchema = dataclasses.field(default_factory=BackendSchema)
     logging: LoggingSchema = dataclasses.field(default_factory=LoggingSchema)
+    privacy: PrivacySchema = dataclasses.field(default_factory=PrivacySchema)
+
+
+def get_config_file_path(config_file: Optional[Path] = None) -> Path:
+    """Find the configuration file to load.
+
+    The locations are checked in the following order, and the first one that
+    applies wins:
+
+    1. The `config_file` argument, given through `clad --config`;
+    2. The `CLAD_CONFIG` environment variable;
+    3. `$XDG_CONFIG_HOME/command-line-assistant/config.toml`, only if the
+       variable is set and the file exists;
+    4. `command-line-assistant/config.toml` in the `$XDG_CONFIG_DIRS`, which
+       defaults to `/etc/xdg`.
 
+    Arguments:
+        config_file (Optional[Path]): The path explicitly given by the user
 
-def load_config_file() -> Config:
+    Raises:
+        FileNotFoundError: In case an explicitly given path doesn't exist
+
+    Returns:
+        Path: The path of the configuration file
+    """
+    explicit_paths: tuple[tuple[str, Optional[Union[str, Path]]], ...] = (
+        ("--config", config_file),
+        (CONFIG_FILE_ENV, os.getenv(CONFIG_FILE_ENV)),
+    )
+    for source, path in explicit_paths:
+        if not path:
+            continue
+
+        explicit_path = Path(path).expanduser()
+        if not explicit_path.is_file():
+            raise FileNotFoundError(
+                f"The configuration file '{explicit_path}' given through {source} doesn't exist."
+            )
+        return explicit_path
+
+    xdg_config_home = os.getenv("XDG_CONFIG_HOME")
+    if xdg_config_home:
+        user_path = Path(xdg_config_home, *CONFIG_FILE_DEFINITION)
+        if user_path.is_file():
+            return user_path
+
+    return Path(get_xdg_config_path(), *CONFIG_FILE_DEFINITION)
+
+
+def load_config_file(config_file: Optional[Path] = None) -> Config:
     """Load the configuration file from the system.
 
+    Arguments:
+        config_file (Optional[Path]): Path of the configuration file to load
+            instead of looking it up. See `get_config_file_path`.
+
     Raises:
         FileNotFoundError: In case the configuration file is missing
         tomllib.TOMLDecodeError: In case it is not possible to decode the config file
+        ValueError: In case any value in the config file is not valid
 
     Returns:
         Config: An instance of the configuration file
     """
     config_dict = {}
-    config_file_path = Path(get_xdg_config_path(), *CONFIG_FILE_DEFINITION)
 
     try:
+        config_file_path = get_config_file_path(config_file)
         print(f"Loading configuration file from {config_file_path}")
         data = config_file_path.read_text()
         config_dict = tomllib.loads(data)
-    except (FileNotFoundError, tomllib.TOMLDecodeError) as ex:
+
+        return Config(
+            database=DatabaseSchema(**config_dict["database"]),
+            history=HistorySchema(**config_dict["history"]),
+            backend=BackendSchema(**config_dict["backend"]),
+            logging=LoggingSchema(**config_dict["logging"]),
+            privacy=PrivacySchema(**config_dict.get("privacy", {})),
+        )
+    except (FileNotFoundError, tomllib.TOMLDecodeError, ValueError) as ex:
         logger.error(ex)
         raise ex
-
-    return Config(
-        database=DatabaseSchema(**config_dict["database"]),
-        history=HistorySchema(**config_dict["history"]),
-        backend=BackendSchema(**config_dict["backend"]),
-        logging=LoggingSchema(**config_dict["logging"]),
-    )
@@ -4,9 +4,81 @@ import dataclasses
 import logging
 import os
 from pathlib import Path
+from typing import Optional
+from urllib.parse import urlparse
+
+from command_line_assistant.utils.template import validate_json_template
 
 logger = logging.getLogger(__name__)
 
+#: Placeholders available to the `request_template`.
+REQUEST_TEMPLATE_VARIABLES: tuple[str, ...] = (
+    "question",
+    "stdin",
+    "attachment.contents",
+    "attachment.mimetype",
+    "terminal.output",
+    "system_info.os",
+    "system_info.version",
+    "system_info.arch",
+    "system_info.id",
+    "cla.version",
+)
+
+#: URL schemes allowed for the backend endpoint.
+ALLOWED_ENDPOINT_SCHEMES: tuple[str, ...] = ("http", "https")
+
+#: URL schemes allowed for the proxies.
+ALLOWED_PROXY_SCHEMES: tuple[str, ...] = ("http", "https", "socks5", "socks5h")
+
+
+def _normalize_endpoint(endpoint: str) -> str:
+    """Normalize the backend endpoint and check that it is a valid URL.
+
+    Arguments:
+        endpoint (str): The endpoint from the config file
+
+    Raises:
+        ValueError: In case the endpoint is not a valid http(s) URL.
+
+    Returns:
+        str: The endpoint without surrounding whitespace and trailing slashes.
+    """
+    normalized = endpoint.strip().rstrip("/")
+    parsed = urlparse(normalized)
+    if parsed.scheme not in ALLOWED_ENDPOINT_SCHEMES or not parsed.netloc:
+        raise ValueError(
+            f"The backend endpoint '{endpoint}' is not a valid URL. It must start with http:// or https://, like 'https://example.com'."
+        )
+
+    return normalized
+
+
+def _validate_proxy(name: str, proxy: str, source: str) -> None:
+    """Check that a proxy is a valid URL.
+
+    Proxies without a scheme, like `username:password@localhost:8002`, are
+    accepted, as they are sent through http by default.
+
+    Arguments:
+        name (str): The protocol the proxy is used for
+        proxy (str): The proxy URL
+        source (str): Where the proxy was set, to be shown in the error
+
+    Raises:
+        ValueError: In case the proxy is not a valid URL.
+    """
+    message = f"The {name} proxy '{proxy}' set in {source} is not a valid URL. It must look like 'http://example-host:8002'."
+    parsed = urlparse(proxy if "://" in proxy else f"http://{proxy}")
+    try:
+        # Accessing the port raises an exception in case it is not a number.
+        _ = parsed.port
+    except ValueError as e:
+        raise ValueError(message) from e
+
+    if parsed.scheme not in ALLOWED_PROXY_SCHEMES or not parsed.hostname:
+        raise ValueError(message)
+
 
 @dataclasses.dataclass
 class AuthSchema:
@@ -42,15 +114,40 @@ class BackendSchema:
     Attributes:
         endpoint (str): The endpoint to communicate with.
         proxies (dict[str, str]): Dictionary of proxies to route the request
+        no_proxy (list[str]): Hosts or domains that are reached without going through the proxies.
         auth (Union[dict, AuthSchema]): The authentication information
         timeout (int): HTTP request timeout in seconds
+        tcp_keepalive_secs (Optional[int]): Enable TCP keep-alive on the backend connections after this many idle seconds.
+        include_citations (bool): Append the sources returned by the backend to the response.
+        slow_request_threshold_ms (int): Log a warning for requests taking longer than this. Zero disables it.
+        retry_on_empty (bool): Retry the request up to 3 times, with a backoff, when the backend returns an empty response. The retries stop once `timeout` seconds have passed since the first request.
+        empty_response_message (str): Message answered to the user when the backend returns an empty response.
+        error_on_empty (bool): Fail the request, instead of answering the `empty_response_message`, when the backend returns an empty response.
+        collapse_repetition (bool): Collapse sentences and paragraphs repeated right after each other in the response.
+        request_template (Optional[str]): JSON template for the request body. The default Lightspeed body is used if not set.
+        response_path (str): Dotted path to the answer in the backend response, like `data.text` or `choices.0.content`.
+        debug_dump_dir (Optional[Path]): Directory to dump every request sent to the backend. Disabled if not set.
+        debug_dump_max_files (int): Maximum number of request dumps to keep in the `debug_dump_dir`.
     """
 
     endpoint: str = "https://0.0.0.0:8080"
     auth: AuthSchema = dataclasses.field(default_factory=AuthSchema)
     timeout: int = 30
+    tcp_keepalive_secs: Optional[int] = None
+    include_citations: bool = False
+    slow_request_threshold_ms: int = 0
+    retry_on_empty: bool = False
+    empty_response_message: str = "The assistant returned no content. Please try asking again."
+    error_on_empty: bool = False
+    collapse_repetition: bool = False
+    request_template: Optional[str] = None
+    response_path: str = "data.text"
 
     proxies: dict[str, str] = dataclasses.field(default_factory=dict)
+    no_proxy: list[str] = dataclasses.field(default_factory=list)
+
+    debug_dump_dir: Optional[Path] = None
+    debug_dump_max_files: int = 50
 
     def __post_init__(self):
         """Post initialization method to normalize values"""
@@ -59,12 +156,51 @@ class BackendSchema:
         if isinstance(self.auth, dict):
             self.auth = AuthSchema(**self.auth)
 
+        self.endpoint = _normalize_endpoint(self.endpoint)
+
+        if self.request_template:
+            try:
+                validate_json_template(
+                    self.request_template, REQUEST_TEMPLATE_VARIABLES
+                )
+            except ValueError as e:
+                raise ValueError(f"Invalid backend request_template. {e}") from e
+
+        self.response_path = self.response_path.strip()
+        if not all(self.response_path.split(".")):
+            raise ValueError(
+                f"The backend response_path '{self.response_path}' is not valid. It must be a dotted path, like 'data.text'."
+            )
+
+        if not self.empty_response_message.strip():
+            raise ValueError(
+                "The backend empty_response_message can't be empty. Enable error_on_empty to fail the request instead."
+            )
+
+        if self.debug_dump_dir:
+            self.debug_dump_dir = Path(self.debug_dump_dir).expanduser()
+
+        if self.debug_dump_max_files < 1:
+            raise ValueError(
+                f"The backend debug_dump_max_files must be at least 1, got {self.debug_dump_max_files}."
+            )
+
         # If the proxies are not set in the config.toml, set the environment variables.
         if not self.proxies:
-            http_proxy = os.environ.get("http_proxy")
-            if http_proxy:
-                self.proxies["http"] = http_proxy
-
-            https_proxy = os.environ.get("https_proxy")
-            if https_proxy:
-                self.proxies["https"] = https_proxy
+            for name in ("http", "https"):
+                proxy = os.environ.get(f"{name}_proxy")
+                if proxy:
+                    _validate_proxy(
+                        name, proxy, f"the {name}_proxy environment variable"
+                    )
+                    self.proxies[name] = proxy
+        else:
+            for name, proxy in self.proxies.items():
+                _validate_proxy(name, proxy, "the config.toml")
+
+        # Same as the proxies, fallback to the environment variable if the
+        # exclusion list is not set in the config.toml.
+        if not self.no_proxy:
+            self.no_proxy = os.environ.get("no_proxy", "").split(",")
+
+        self.no_proxy = [host.strip() for host in self.no_proxy if host.strip()]
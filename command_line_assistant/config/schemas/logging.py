@@ -20,12 +20,14 @@ class LoggingSchema:
 
     Attributes:
         level (str): The level to log. Defaults to "INFO".
+        format (str): The format of the daemon logs, either "text" or "json". Defaults to "text".
         responses (bool): If the responses should be logged. Defaults to True.
         question (bool): If the questions should be logged. Defaults to True.
         users (dict[str, dict[str, bool]]): A dictionary of users and their logging preferences.
     """
 
     level: str = "INFO"
+    format: str = "text"
     audit: AuditSchema = dataclasses.field(default_factory=AuditSchema)
 
     def __post_init__(self) -> None:
@@ -33,6 +35,7 @@ class LoggingSchema:
 
         Raises:
             ValueError: In case the requested level i snot in the allowed_levels list.
+            ValueError: In case the requested format is not in the allowed_formats list.
         """
         level = self.level.upper()
         allowed_levels = ("CRITICAL", "ERROR", "WARNING", "INFO", "DEBUG", "NOTSET")
@@ -43,5 +46,14 @@ class LoggingSchema:
 
         self.level = level
 
+        log_format = self.format.lower()
+        allowed_formats = ("text", "json")
+        if log_format not in allowed_formats:
+            raise ValueError(
+                f"The requested format '{self.format}' is not allowed. Choose from: {', '.join(allowed_formats)}"
+            )
+
+        self.format = log_format
+
         if isinstance(self.audit, dict):
             self.audit = AuditSchema(**self.audit)
@@ -0,0 +1,15 @@
+"""Schemas for the privacy config."""
+
+import dataclasses
+
+
+@dataclasses.dataclass
+class PrivacySchema:
+    """This class represents the [privacy] section of our config.toml file.
+
+    Attributes:
+        send_system_info (bool): Define if the system information (OS, version,
+            architecture and OS id) should be sent to the backend.
+    """
+
+    send_system_info: bool = True
@@ -2,22 +2,44 @@
 
 import logging
 import sys
+from argparse import ArgumentParser, Namespace
+from pathlib import Path
 
-from command_line_assistant.config import load_config_file
+from command_line_assistant.config import CONFIG_FILE_ENV, load_config_file
 from command_line_assistant.dbus.server import serve
 from command_line_assistant.logger import setup_daemon_logging
 
 logger = logging.getLogger(__name__)
 
 
+def _parse_args() -> Namespace:
+    """Parse the command line arguments of clad.
+
+    Returns:
+        Namespace: The parsed arguments.
+    """
+    parser = ArgumentParser(prog="clad", description="Command Line Assistant Daemon")
+    parser.add_argument(
+        "--config",
+        type=Path,
+        help=(
+            "Path to the configuration file. Takes precedence over the "
+            f"{CONFIG_FILE_ENV} environment variable and the XDG locations."
+        ),
+    )
+    return parser.parse_args()
+
+
 def daemonize() -> int:
     """Main start point for the clad binary.
 
     Returns:
         int: The status code.
     """
+    args = _parse_args()
+
     # Load up the configuration file
-    config = load_config_file()
+    config = load_config_file(args.config)
     setup_daemon_logging(config)
     serve(config)
 
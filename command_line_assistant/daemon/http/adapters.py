@@ -1,9 +1,46 @@
 """Main module to track the adapters for the http backend."""
 
-from typing import Union
+import socket
+from typing import Any, Optional, Union
 
 from requests.adapters import HTTPAdapter
 from urllib3 import Retry
+from urllib3.connection import HTTPConnection
+
+#: Default amount of retries for the requests to the backend.
+DEFAULT_MAX_RETRIES: int = 3
+
+#: Backoff factor between the retries, in seconds. The n-th retry waits for
+#: `DEFAULT_BACKOFF_FACTOR * 2 ** (n - 1)` seconds.
+DEFAULT_BACKOFF_FACTOR: float = 0.1
+
+
+def get_keepalive_socket_options(
+    tcp_keepalive_secs: Optional[int],
+) -> list[tuple[int, int, int]]:
+    """Build the socket options needed to enable TCP keep-alive.
+
+    Arguments:
+        tcp_keepalive_secs (Optional[int]): Idle time in seconds before sending
+            keep-alive probes. Keep-alive is not enabled if not set.
+
+    Returns:
+        list[tuple[int, int, int]]: The default socket options plus the
+        keep-alive ones, if requested.
+    """
+    options = list(HTTPConnection.default_socket_options)
+    if not tcp_keepalive_secs:
+        return options
+
+    options.append((socket.SOL_SOCKET, socket.SO_KEEPALIVE, 1))
+    # Not every platform exposes the fine-grained keep-alive knobs.
+    for name in ("TCP_KEEPIDLE", "TCP_KEEPINTVL"):
+        if hasattr(socket, name):
+            options.append(
+                (socket.IPPROTO_TCP, getattr(socket, name), tcp_keepalive_secs)
+            )
+
+    return options
 
 
 class RetryAdapter(HTTPAdapter):
@@ -13,8 +50,9 @@ class RetryAdapter(HTTPAdapter):
         self,
         pool_connections: int = 10,
         pool_maxsize: int = 10,
-        max_retries: Union[int, None] = 3,
+        max_retries: Union[int, None] = DEFAULT_MAX_RETRIES,
         pool_block: bool = False,
+        tcp_keepalive_secs: Optional[int] = None,
     ) -> None:
         """Constructor of the class.
 
@@ -23,11 +61,36 @@ class RetryAdapter(HTTPAdapter):
             pool_maxsize (int, optional): The max size of the pool. Defaults to 10.
             max_retries (Union[Retry, int, None], optional): The maximum number of retires. Defaults to 3.
             pool_block (bool, optional): If the pool should be blocked. Defaults to False.
+            tcp_keepalive_secs (Optional[int], optional): Enable TCP keep-alive with the given idle time. Defaults to None.
         """
+        self._socket_options = get_keepalive_socket_options(tcp_keepalive_secs)
         retries = Retry(
             total=max_retries,
-            backoff_factor=0.1,
+            backoff_factor=DEFAULT_BACKOFF_FACTOR,
             status_forcelist=[502, 503, 504],
             allowed_methods={"POST"},
         )
         super().__init__(pool_connections, pool_maxsize, retries, pool_block)
+
+    def init_poolmanager(self, *args: Any, **kwargs: Any) -> None:
+        """Initialize the pool manager with our socket options.
+
+        Arguments:
+            args (Any): Positional arguments for the pool manager
+            kwargs (Any): Keyword arguments for the pool manager
+        """
+        kwargs["socket_options"] = self._socket_options
+        super().init_poolmanager(*args, **kwargs)
+
+    def proxy_manager_for(self, proxy: str, **proxy_kwargs: Any) -> Any:
+        """Retrieve the manager for the given proxy with our socket options.
+
+        Arguments:
+            proxy (str): The proxy to return a manager for
+            proxy_kwargs (Any): Extra keyword arguments for the proxy manager
+
+        Returns:
+            Any: The proxy manager, for both http(s) and SOCKS proxies
+        """
+        proxy_kwargs["socket_options"] = self._socket_options
+        return super().proxy_manager_for(proxy, **proxy_kwargs)
@@ -1,17 +1,42 @@
 """Module to handle the query submission to the backend."""
 
+import json
 import logging
+import os
+import re
+import tempfile
+import time
+import uuid
+from datetime import datetime
 from http import HTTPStatus
 from json.decoder import JSONDecodeError
+from pathlib import Path
+from typing import Any, NoReturn, Optional
 
 from requests import RequestException, Response
+from requests.exceptions import ConnectionError as RequestConnectionError
+from requests.exceptions import SSLError, Timeout
+from urllib3.exceptions import TimeoutError as PoolTimeoutError
 
 from command_line_assistant.config import Config
-from command_line_assistant.daemon.http.session import get_session
-from command_line_assistant.dbus.exceptions import RequestFailedError
+from command_line_assistant.daemon.http.adapters import (
+    DEFAULT_BACKOFF_FACTOR,
+    DEFAULT_MAX_RETRIES,
+)
+from command_line_assistant.daemon.http.session import get_request_proxies, get_session
+from command_line_assistant.dbus.exceptions import (
+    BackendTimeoutError,
+    BackendUnreachableError,
+    RateLimitExceededError,
+    RequestFailedError,
+)
+from command_line_assistant.utils.files import create_folder
 
 logger = logging.getLogger(__name__)
 
+#: Split a paragraph into sentences, keeping the punctuation in each sentence.
+SENTENCE_SEPARATOR = re.compile(r"(?<=[.!?]) +")
+
 #: Map status codes to error messages
 ERROR_MESSAGES: dict[int, str] = {
     # 4xx status codes
@@ -62,6 +87,8 @@ def submit(payload: dict, config: Config) -> str:
         config: Configuration object with backend endpoint information
 
     Raises:
+        BackendTimeoutError: If the server doesn't answer in time
+        BackendUnreachableError: If the connection to the server fails
         RequestFailedError: If the request fails due to network issues,
                            authentication problems, or server errors
 
@@ -70,16 +97,69 @@ def submit(payload: dict, config: Config) -> str:
     """
     query_endpoint = f"{config.backend.endpoint}/infer"
 
+    if config.backend.debug_dump_dir:
+        _dump_request(
+            payload, config.backend.debug_dump_dir, config.backend.debug_dump_max_files
+        )
+
+    start = time.monotonic_ns()
     try:
-        response = _send_request(query_endpoint, payload, config)
-        logger.info("Received response from LLM backend")
+        response = _query_backend(query_endpoint, payload, config)
+        text = _extract_response_text(response, config.backend.response_path)
+
+        # Empty responses are usually a transient issue in the backend, so
+        # retry them with the same budget and backoff we use for the failed
+        # requests. The retries share the timeout of the request, so they
+        # never keep clad busy for much longer than a single request would.
+        retries = DEFAULT_MAX_RETRIES if config.backend.retry_on_empty else 0
+        for attempt in range(1, retries + 1):
+            if text.strip():
+                break
+
+            backoff = DEFAULT_BACKOFF_FACTOR * 2 ** (attempt - 1)
+            elapsed = (time.monotonic_ns() - start) / 1e9
+            remaining = config.backend.timeout - elapsed - backoff
+            if remaining <= 0:
+                logger.warning(
+                    "Not retrying the empty response, as the timeout of %s seconds was reached.",
+                    config.backend.timeout,
+                )
+                break
+
+            logger.warning(
+                "Received an empty response from LLM backend. Retrying (%s/%s).",
+                attempt,
+                retries,
+            )
+            time.sleep(backoff)
+            response = _query_backend(query_endpoint, payload, config, remaining)
+            text = _extract_response_text(response, config.backend.response_path)
+
+        if not text.strip():
+            logger.warning("Received an empty response from LLM backend.")
+            if config.backend.error_on_empty:
+                raise RequestFailedError(
+                    "The server returned an empty response. Please try again in a few minutes."
+                )
+            return config.backend.empty_response_message
+
+        if config.backend.collapse_repetition:
+            text = _collapse_repetition(text)
 
-        if response.status_code != HTTPStatus.OK:
-            _handle_error_response(response)
+        if config.backend.include_citations:
+            text += _format_citations(response)
 
-        return _extract_response_text(response)
+        return text
     except RequestException as exc:
         logger.error("Failed to get response from AI: %s", exc)
+        if _is_timeout(exc):
+            raise BackendTimeoutError(
+                f"The server didn't answer within {config.backend.timeout} seconds. Please try again in a few minutes."
+            ) from exc
+        if isinstance(exc, RequestConnectionError) and not isinstance(exc, SSLError):
+            raise BackendUnreachableError(
+                f"Couldn't connect to the server at {config.backend.endpoint}. Please check the network and proxy settings."
+            ) from exc
         raise RequestFailedError(
             f"Communication error with the server: {str(exc)}. Please try again in a few minutes."
         ) from exc
@@ -91,15 +171,154 @@ def submit(payload: dict, config: Config) -> str:
                 "The system must be registered to use RHEL Lightspeed. For cloud-based systems, see: https://access.redhat.com/articles/7127962"
             ) from exc
         raise exc
+    finally:
+        # Include the retries and the processing of the response, so the
+        # latency of every query is known even when it isn't slow.
+        elapsed_ms = (time.monotonic_ns() - start) / 1e6
+        logger.debug("Handled the query to %s in %.0f ms", query_endpoint, elapsed_ms)
+
+
+def _is_timeout(exc: RequestException) -> bool:
+    """Check if a failed request timed out, either connecting or reading.
+
+    Args:
+        exc: The exception raised by the request
+
+    Returns:
+        True if the request timed out, False otherwise
+    """
+    if isinstance(exc, Timeout):
+        return True
+
+    # Once the retries are exhausted, requests wraps the read timeouts in a
+    # ConnectionError, keeping the original error as the reason.
+    reason = getattr(exc.args[0], "reason", None) if exc.args else None
+    return isinstance(reason, PoolTimeoutError)
+
+
+def _query_backend(
+    endpoint: str, payload: dict, config: Config, timeout: Optional[float] = None
+) -> Response:
+    """Send the request to the backend and check the response for errors.
+
+    Args:
+        endpoint: Full URL endpoint
+        payload: Request payload
+        config: Configuration with backend settings
+        timeout: Timeout of the request in seconds, instead of the configured one
 
+    Raises:
+        RequestFailedError: If response status code indicates an error
+
+    Returns:
+        Response object
+    """
+    start = time.monotonic_ns()
+    response = _send_request(endpoint, payload, config, timeout)
+    elapsed_ms = (time.monotonic_ns() - start) / 1e6
+    logger.info("Received response from LLM backend")
+
+    threshold_ms = config.backend.slow_request_threshold_ms
+    if _is_slow_request(elapsed_ms, threshold_ms):
+        logger.warning(
+            "Request to %s took %.0f ms, exceeding the threshold of %s ms",
+            endpoint,
+            elapsed_ms,
+            threshold_ms,
+        )
+
+    if response.status_code != HTTPStatus.OK:
+        _handle_error_response(response)
+
+    return response
+
+
+def _is_slow_request(elapsed_ms: float, threshold_ms: int) -> bool:
+    """Check if a request took longer than the configured threshold.
+
+    Args:
+        elapsed_ms: Time taken by the request in milliseconds
+        threshold_ms: The threshold in milliseconds. Zero disables the check.
+
+    Returns:
+        True if the request exceeded the threshold, False otherwise
+    """
+    return threshold_ms > 0 and elapsed_ms > threshold_ms
+
+
+def _dump_filename() -> str:
+    """Generate an unique filename for a request dump.
+
+    The filename starts with the current timestamp so the dumps can be sorted
+    by name, followed by a short random identifier to avoid collisions.
+
+    Returns:
+        str: The filename for the request dump
+    """
+    timestamp = datetime.now().strftime("%Y%m%dT%H%M%S%f")
+    return f"request-{timestamp}-{uuid.uuid4().hex[:8]}.json"
+
+
+def _dump_request(payload: dict, dump_dir: Path, max_files: int) -> None:
+    """Write the request payload to the debug dump directory.
+
+    Notes:
+        Failures to write the dump are only logged, as this is a debug
+        functionality and should never prevent the request from being sent.
+
+    Args:
+        payload: Request payload
+        dump_dir: Directory where the dumps will be placed
+        max_files: Maximum number of dumps to keep in the directory
+    """
+    temporary_file = None
+    try:
+        create_folder(dump_dir, parents=True)
+        # Write to a temporary file first and move it in place afterwards, so
+        # nobody reads a partially written dump.
+        with tempfile.NamedTemporaryFile(
+            mode="w", dir=dump_dir, prefix=".request-", suffix=".tmp", delete=False
+        ) as handler:
+            temporary_file = Path(handler.name)
+            json.dump(payload, handler, indent=2)
+
+        dump_file = Path(dump_dir, _dump_filename())
+        os.replace(temporary_file, dump_file)
+        temporary_file = None
+        logger.debug("Dumped backend request to %s", dump_file)
+
+        _rotate_dumps(dump_dir, max_files)
+    except OSError as e:
+        logger.warning("Failed to dump backend request to %s: %s", dump_dir, e)
+    finally:
+        # The temporary file is only left behind if the dump failed before it
+        # was moved in place.
+        if temporary_file:
+            temporary_file.unlink(missing_ok=True)
+
+
+def _rotate_dumps(dump_dir: Path, max_files: int) -> None:
+    """Remove the oldest request dumps exceeding the maximum number of files.
+
+    Args:
+        dump_dir: Directory where the dumps are placed
+        max_files: Maximum number of dumps to keep in the directory
+    """
+    dumps = sorted(dump_dir.glob("request-*.json"))
+    for dump in dumps[: max(len(dumps) - max_files, 0)]:
+        dump.unlink(missing_ok=True)
 
-def _send_request(endpoint: str, payload: dict, config: Config) -> Response:
+
+def _send_request(
+    endpoint: str, payload: dict, config: Config, timeout: Optional[float] = None
+) -> Response:
     """Send POST request to the backend.
 
     Args:
         endpoint: Full URL endpoint
         payload: Request payload
         config: Configuration with auth settings
+        timeout: Timeout of the request in seconds, instead of the configured one
 
     Returns:
         Response object
@@ -108,7 +327,8 @@ def _send_request(endpoint: str, payload: dict, config: Config) -> Response:
         return session.post(
             endpoint,
             json=payload,  # Uses json parameter instead of manually serializing
-            timeout=config.backend.timeout,
+            timeout=timeout or config.backend.timeout,
+            proxies=get_request_proxies(config),
         )
 
 
@@ -119,7 +339,8 @@ def _handle_error_response(response: Response) -> None:
         response: Response object to check
 
     Raises:
-        RequestFailedError: If response status code indicates an error
+        RateLimitExceededError: If the rate limit of the server was exceeded
+        RequestFailedError: If response status code indicates any other error
     """
     # Get the error message from the predefined map, or create a generic one
     error_message = ERROR_MESSAGES.get(
@@ -147,21 +368,191 @@ def _handle_error_response(response: Response) -> None:
     error_message = error_message.format(detailed_message=detailed_message)
 
     logger.error("Status code: %s and message: %s", response.status_code, error_message)
+    if response.status_code == HTTPStatus.TOO_MANY_REQUESTS:
+        retry_after = response.headers.get("Retry-After")
+        if retry_after:
+            unit = " seconds" if retry_after.isdigit() else ""
+            error_message += f" Retry after {retry_after}{unit}."
+        raise RateLimitExceededError(error_message)
+
     raise RequestFailedError(error_message)
 
 
-def _extract_response_text(response: Response) -> str:
+def _extract_response_text(response: Response, response_path: str) -> str:
     """Extract text from successful response.
 
     Args:
         response: Response object with JSON data
+        response_path: Dotted path to the text in the JSON data
+
+    Raises:
+        RequestFailedError: If the response doesn't contain text in the given path
 
     Returns:
         Extracted text from response
     """
     try:
         data = response.json()
-        return data.get("data", {}).get("text", "")
     except ValueError:
         logger.warning("Response didn't contain valid JSON")
         return response.text or ""
+
+    # A missing or null text is an empty answer, which is handled by the
+    # retries and the fallback message. Only a path that doesn't match the
+    # structure of the response is an error.
+    parent_path, _, leaf = response_path.rpartition(".")
+    try:
+        parent = _resolve_response_path(data, parent_path) if parent_path else data
+    except LookupError:
+        _raise_missing_text(response_path, data)
+
+    if isinstance(parent, dict):
+        text = parent.get(leaf)
+    elif isinstance(parent, list) and leaf.isdigit():
+        text = parent[int(leaf)] if int(leaf) < len(parent) else None
+    else:
+        _raise_missing_text(response_path, data)
+
+    if text is None:
+        logger.debug("Response has no text in '%s': %s", response_path, data)
+        return ""
+
+    if not isinstance(text, str):
+        _raise_missing_text(response_path, data)
+
+    return text
+
+
+def _raise_missing_text(response_path: str, data: Any) -> NoReturn:
+    """Fail the request as the response path doesn't point to any text.
+
+    Args:
+        response_path: Dotted path to the text in the JSON data
+        data: The JSON data from the response
+
+    Raises:
+        RequestFailedError: Always, with a message pointing to the setting
+    """
+    logger.error(
+        "Response path '%s' doesn't point to text in response: %s",
+        response_path,
+        data,
+    )
+    raise RequestFailedError(
+        f"The response from the server doesn't contain any text in '{response_path}'. Please check the backend response_path setting."
+    )
+
+
+def _resolve_response_path(data: Any, response_path: str) -> Any:
+    """Walk the JSON data following a dotted path.
+
+    Each part of the path is either an object key or, for lists, the index of
+    the item, like `choices.0.message.content`.
+
+    Args:
+        data: The JSON data from the response
+        response_path: Dotted path to walk
+
+    Raises:
+        LookupError: If any part of the path is missing in the data
+
+    Returns:
+        The value at the end of the path
+    """
+    for part in response_path.split("."):
+        if isinstance(data, dict):
+            data = data[part]
+        elif isinstance(data, list) and part.isdigit():
+            data = data[int(part)]
+        else:
+            raise KeyError(part)
+
+    return data
+
+
+def _format_citations(response: Response) -> str:
+    """Format the citations from a successful response as a sources section.
+
+    The citations are expected as a list under `data.citations`, where each
+    citation is either a plain string or an object with `title` and `url`
+    (or `link`) fields.
+
+    Args:
+        response: Response object with JSON data
+
+    Returns:
+        The formatted sources section, or an empty string if there are no citations
+    """
+    try:
+        data = response.json()
+    except ValueError:
+        return ""
+
+    # Backends with a custom `response_path` may not answer with an object
+    # under `data`, in which case there are no citations to look for.
+    data = data.get("data") if isinstance(data, dict) else None
+    if not isinstance(data, dict):
+        return ""
+
+    citations = data.get("citations", [])
+    if not isinstance(citations, list):
+        logger.debug("Ignoring citations as they are not a list: %s", citations)
+        return ""
+
+    sources = []
+    for citation in citations:
+        if isinstance(citation, dict):
+            title = citation.get("title")
+            url = citation.get("url") or citation.get("link")
+            source = f"{title}: {url}" if title and url else title or url
+        else:
+            source = str(citation)
+
+        if source:
+            sources.append(f"- {source}")
+
+    if not sources:
+        return ""
+
+    return "\n\nSources:\n" + "\n".join(sources)
+
+
+def _collapse_repetition(text: str) -> str:
+    """Collapse sentences and paragraphs repeated right after each other.
+
+    Some models get stuck in a loop and repeat the same sentence or paragraph
+    over and over. Only exact and consecutive repetitions are collapsed, and
+    code blocks are left untouched, to avoid removing intentional repetition.
+
+    Args:
+        text: The response text from the backend
+
+    Returns:
+        The text without the consecutive repetitions
+    """
+    paragraphs: list[str] = []
+    previous_prose = ""
+    in_code_block = False
+
+    for paragraph in text.split("\n\n"):
+        fences = paragraph.count("```")
+        if in_code_block or fences:
+            paragraphs.append(paragraph)
+            previous_prose = ""
+            in_code_block ^= fences % 2 == 1
+            continue
+
+        sentences: list[str] = []
+        for sentence in SENTENCE_SEPARATOR.split(paragraph):
+            if not sentences or sentences[-1] != sentence:
+                sentences.append(sentence)
+        paragraph = " ".join(sentences)
+
+        if paragraph.strip() and paragraph.strip() == previous_prose:
+            logger.debug("Collapsing a repeated paragraph in the response.")
+            continue
+
+        paragraphs.append(paragraph)
+        previous_prose = paragraph.strip()
+
+    return "\n\n".join(paragraphs)
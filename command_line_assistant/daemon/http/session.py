@@ -3,6 +3,7 @@
 import logging
 
 from requests.sessions import Session
+from requests.utils import should_bypass_proxies
 
 from command_line_assistant.config import Config
 from command_line_assistant.constants import VERSION
@@ -31,17 +32,43 @@ def get_session(config: Config) -> Session:
     """
     session = Session()
 
-    # Include the proxies defined by the user. By default, nothing is loaded.
-    session.proxies.update(config.backend.proxies)
+    no_proxy = ",".join(config.backend.no_proxy)
+    if no_proxy and should_bypass_proxies(config.backend.endpoint, no_proxy):
+        logger.debug(
+            "Skipping the proxies as %s is in the no_proxy list.",
+            config.backend.endpoint,
+        )
+    else:
+        # Include the proxies defined by the user. By default, nothing is loaded.
+        session.proxies.update(config.backend.proxies)
 
     # Set up the necessary headers for every session.
     session.headers["User-Agent"] = USER_AGENT
     session.headers["Content-Type"] = "application/json"
 
-    retry_adapter = RetryAdapter()
+    retry_adapter = RetryAdapter(
+        tcp_keepalive_secs=config.backend.tcp_keepalive_secs
+    )
 
     session.mount(config.backend.endpoint, retry_adapter)
 
     session.cert = (config.backend.auth.cert_file, config.backend.auth.key_file)  # type: ignore
 
     return session
+
+
+def get_request_proxies(config: Config) -> dict[str, str]:
+    """Retrieve the proxies to be given to each request to the backend.
+
+    Notes:
+        Requests only honors a `no_proxy` exclusion list for the proxies taken
+        from the environment when it is given per request. Passing it this way
+        keeps the rest of the environment, like `REQUESTS_CA_BUNDLE`, in use.
+
+    Arguments:
+        config (Config): Instance of the config class
+
+    Returns:
+        dict[str, str]: The proxies with the `no_proxy` exclusion list.
+    """
+    return {"no_proxy": ",".join(config.backend.no_proxy)}
@@ -17,6 +17,21 @@ class RequestFailedError(DBusError):
     """Failed submit a request to the server."""
 
 
+@dbus_error("RateLimitExceededError", namespace=CHAT_NAMESPACE)
+class RateLimitExceededError(RequestFailedError):
+    """The server refused the request as the rate limit was exceeded."""
+
+
+@dbus_error("BackendTimeoutError", namespace=CHAT_NAMESPACE)
+class BackendTimeoutError(RequestFailedError):
+    """The server didn't answer the request in time."""
+
+
+@dbus_error("BackendUnreachableError", namespace=CHAT_NAMESPACE)
+class BackendUnreachableError(RequestFailedError):
+    """Couldn't connect to the server."""
+
+
 @dbus_error("CorruptedHistoryError", namespace=HISTORY_NAMESPACE)
 class CorruptedHistoryError(DBusError):
     """History is corrupted and we can't do anything against it."""
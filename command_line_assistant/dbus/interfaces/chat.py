@@ -2,7 +2,7 @@
 
 import logging
 from dataclasses import dataclass
-from typing import Any
+from typing import Any, Optional
 
 from dasbus.server.interface import dbus_interface
 from dasbus.server.template import InterfaceTemplate
@@ -23,7 +23,9 @@ from command_line_assistant.dbus.structures.chat import (
     ChatList,
     Question,
     Response,
+    SystemInfo,
 )
+from command_line_assistant.utils.template import render_json_template
 
 logger = logging.getLogger(__name__)
 
@@ -33,6 +35,31 @@ class InferencePayload:
     """The payload to be submitted to the backend for inference."""
 
     content: Question
+    send_system_info: bool = True
+    request_template: Optional[str] = None
+
+    def to_template_variables(self) -> dict[str, str]:
+        """Build the values for the placeholders of a request template.
+
+        Returns:
+            dict[str, str]: The value for each placeholder.
+        """
+        systeminfo = self.content.systeminfo
+        if not self.send_system_info:
+            systeminfo = SystemInfo()
+
+        return {
+            "question": self.content.message,
+            "stdin": self.content.stdin.stdin,
+            "attachment.contents": self.content.attachment.contents,
+            "attachment.mimetype": self.content.attachment.mimetype,
+            "terminal.output": self.content.terminal.output,
+            "system_info.os": systeminfo.os,
+            "system_info.version": systeminfo.version,
+            "system_info.arch": systeminfo.arch,
+            "system_info.id": systeminfo.id,
+            "cla.version": VERSION,
+        }
 
     def to_dict(self) -> dict[str, Any]:
         """Turn content into dictionary for submission to the backend.
@@ -40,25 +67,31 @@ class InferencePayload:
         Returns:
             dict[str, Any]: The content in dictionary format.
         """
-        return {
-            "question": self.content.message,
-            "context": {
-                "stdin": self.content.stdin.stdin,
-                "attachments": {
-                    "contents": self.content.attachment.contents,
-                    "mimetype": self.content.attachment.mimetype,
-                },
-                "terminal": {"output": self.content.terminal.output},
-                "systeminfo": {
-                    "os": self.content.systeminfo.os,
-                    "version": self.content.systeminfo.version,
-                    "arch": self.content.systeminfo.arch,
-                    "id": self.content.systeminfo.id,
-                },
-                "cla": {"version": VERSION},
+        if self.request_template:
+            return render_json_template(
+                self.request_template, self.to_template_variables()
+            )
+
+        context = {
+            "stdin": self.content.stdin.stdin,
+            "attachments": {
+                "contents": self.content.attachment.contents,
+                "mimetype": self.content.attachment.mimetype,
             },
+            "terminal": {"output": self.content.terminal.output},
         }
 
+        if self.send_system_info:
+            context["systeminfo"] = {
+                "os": self.content.systeminfo.os,
+                "version": self.content.systeminfo.version,
+                "arch": self.content.systeminfo.arch,
+                "id": self.content.systeminfo.id,
+            }
+
+        context["cla"] = {"version": VERSION}
+        return {"question": self.content.message, "context": context}
+
 
 @dbus_interface(CHAT_IDENTIFIER.interface_name)
 class ChatInterface(InterfaceTemplate, DBusAuthorizationMixin):
@@ -293,7 +326,12 @@ class ChatInterface(InterfaceTemplate, DBusAuthorizationMixin):
         self._verify_caller_authorization(sender, user_id)
         # Submit query to backend
         content = Question.from_structure(message_input)
-        payload = InferencePayload(content)
+        config = self.implementation.config
+        payload = InferencePayload(
+            content,
+            config.privacy.send_system_info,
+            config.backend.request_template,
+        )
 
         logger.info(
             "Submitting question from user.",
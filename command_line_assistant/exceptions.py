@@ -1,5 +1,7 @@
 """Module that holds the exceptions for the client part of the codebase."""
 
+import os
+
 
 class StopInteractiveMode(Exception):
     """Control the interactive mode execution"""
@@ -11,6 +13,12 @@ class ChatCommandException(Exception):
     code: int = 80
 
 
+class ChatUsageException(ChatCommandException):
+    """Exception class to signal an invalid usage of the chat command."""
+
+    code: int = os.EX_USAGE
+
+
 class ShellCommandException(Exception):
     """Exception class to control shell command."""
 
@@ -1,12 +1,22 @@
 """Hold any shell integration that powers the tool."""
 
+import shlex
+import shutil
+from argparse import SUPPRESS, Action, ArgumentParser, _SubParsersAction
+
+#: Default location of the c binary used when it can't be found in the $PATH.
+DEFAULT_C_BINARY: str = "/usr/bin/c"
+
+#: Placeholder replaced by the quoted path of the c binary in the integrations.
+C_BINARY_PLACEHOLDER: str = "@C_BINARY@"
+
 #: Bash interactive session for c.
 BASH_INTERACTIVE: str = r"""
 # Command Line Assistant Interactive Mode Integration
 __c_interactive() {
     # Save current terminal state
     local old_tty=$(stty -g)
-    local c_binary=/usr/bin/c
+    local c_binary=@C_BINARY@
 
     # Function to restore terminal state
     cleanup() {
@@ -22,8 +32,8 @@ __c_interactive() {
     stty icanon # Enable canonical mode (line-by-line input)
 
     # Start interactive mode
-    if command -v $c_binary >/dev/null 2>&1; then
-        $c_binary --interactive
+    if command -v "$c_binary" >/dev/null 2>&1; then
+        "$c_binary" --interactive
     else
         echo "Error: Command Line Assistant is not installed"
         return 1
@@ -36,3 +46,394 @@ __c_interactive() {
 # Bind Ctrl+g to the interactive function
 bind -x '"\C-g": __c_interactive'
 """
+
+#: Alias for bash and zsh, asking everything after `??` as a single question.
+#: The alias points to a function, as `??` would be expanded as a glob
+#: pattern if it was the name of the function itself.
+POSIX_ALIAS: str = r"""__c_ask() {
+    @C_BINARY@ chat "$*"
+}
+alias '??'='__c_ask'
+"""
+
+#: Alias for zsh. Same as the bash one, but the arguments are not expanded as
+#: glob patterns, as zsh aborts the command when a pattern doesn't match any
+#: file, which happens with almost every question ending in `?`.
+ZSH_ALIAS: str = r"""__c_ask() {
+    @C_BINARY@ chat "$*"
+}
+alias '??'='noglob __c_ask'
+"""
+
+#: Alias for fish, asking everything after `??` as a single question. An
+#: abbreviation is used, as fish could expand `??` as a glob pattern.
+FISH_ALIAS: str = r"""function __c_ask
+    @C_BINARY@ chat "$argv"
+end
+abbr --add -- '??' __c_ask
+"""
+
+
+def quote_shell_value(value: str) -> str:
+    """Quote a value to be safely embedded in a shell snippet.
+
+    The result is always pure ASCII, so it doesn't break the rc files of the
+    user. Values with non-ASCII characters are written with the ANSI-C
+    quoting (`$'...'`), escaping each non-ASCII byte.
+
+    Arguments:
+        value (str): The value to be quoted
+
+    Returns:
+        str: The quoted value
+    """
+    if value.isascii():
+        return shlex.quote(value)
+
+    escaped = "".join(
+        (
+            chr(byte)
+            if 0x20 <= byte < 0x7F and chr(byte) not in "'\\"
+            else f"\\x{byte:02x}"
+        )
+        for byte in value.encode("utf-8")
+    )
+    return f"$'{escaped}'"
+
+
+def quote_fish_value(value: str) -> str:
+    """Quote a value to be safely embedded in a fish snippet.
+
+    Fish doesn't understand the ANSI-C quoting used by `quote_shell_value`,
+    so values that need quoting are single quoted, escaping only the
+    backslashes and the single quotes. Non-ASCII characters are kept as they
+    are.
+
+    Arguments:
+        value (str): The value to be quoted
+
+    Returns:
+        str: The quoted value
+    """
+    if shlex.quote(value) == value:
+        return value
+
+    escaped = value.replace("\\", "\\\\").replace("'", "\\'")
+    return f"'{escaped}'"
+
+
+def get_c_binary() -> str:
+    """Find the path of the c binary, falling back to the default location.
+
+    Returns:
+        str: The path of the c binary
+    """
+    return shutil.which("c") or DEFAULT_C_BINARY
+
+
+def generate_bash_interactive(c_binary: str = DEFAULT_C_BINARY) -> str:
+    """Generate the bash interactive integration for the given c binary.
+
+    Arguments:
+        c_binary (str): The path of the c binary to be called by the integration
+
+    Returns:
+        str: The bash interactive integration
+    """
+    return BASH_INTERACTIVE.replace(C_BINARY_PLACEHOLDER, quote_shell_value(c_binary))
+
+
+def generate_alias(
+    fish: bool = False, c_binary: str = DEFAULT_C_BINARY, zsh: bool = False
+) -> str:
+    """Generate the `??` alias for the given c binary.
+
+    Arguments:
+        fish (bool): Generate the alias with the fish syntax instead of the bash one
+        c_binary (str): The path of the c binary to be called by the alias
+        zsh (bool): Generate the alias for zsh instead of bash
+
+    Returns:
+        str: The alias definition
+    """
+    if fish:
+        return FISH_ALIAS.replace(C_BINARY_PLACEHOLDER, quote_fish_value(c_binary))
+
+    alias = ZSH_ALIAS if zsh else POSIX_ALIAS
+    return alias.replace(C_BINARY_PLACEHOLDER, quote_shell_value(c_binary))
+
+
+def _get_subcommands(parser: ArgumentParser) -> dict[str, ArgumentParser]:
+    """Retrieve the subcommands registered in the given parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser to inspect
+
+    Returns:
+        dict[str, ArgumentParser]: Mapping of the subcommand name and its parser
+    """
+    for action in parser._actions:
+        if isinstance(action, _SubParsersAction):
+            return dict(action.choices)
+
+    return {}
+
+
+def _get_subcommand_helps(parser: ArgumentParser) -> dict[str, str]:
+    """Retrieve the help message of each subcommand in the given parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser to inspect
+
+    Returns:
+        dict[str, str]: Mapping of the subcommand name and its help message
+    """
+    for action in parser._actions:
+        if isinstance(action, _SubParsersAction):
+            return {
+                choice.dest: _get_help(choice) for choice in action._choices_actions
+            }
+
+    return {}
+
+
+def _get_help(action: Action) -> str:
+    """Retrieve a single line help message for the given action.
+
+    Arguments:
+        action (Action): The action to retrieve the help from
+
+    Returns:
+        str: The help message, or an empty string if there is none
+    """
+    if not action.help or action.help == SUPPRESS:
+        return ""
+
+    return " ".join(action.help.split())
+
+
+def _get_options(parser: ArgumentParser) -> list[Action]:
+    """Retrieve the optional arguments registered in the given parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser to inspect
+
+    Returns:
+        list[Action]: The actions that represent an optional argument
+    """
+    return [action for action in parser._actions if action.option_strings]
+
+
+def _get_option_strings(parser: ArgumentParser) -> list[str]:
+    """Retrieve all the option strings (e.g. -h and --help) of a parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser to inspect
+
+    Returns:
+        list[str]: The option strings in the order they were registered
+    """
+    return [
+        option for action in _get_options(parser) for option in action.option_strings
+    ]
+
+
+def _get_value_options(
+    parsers: list[ArgumentParser],
+) -> tuple[dict[str, list[str]], list[str]]:
+    """Retrieve the options that expect a value in the given parsers.
+
+    Options with a fixed set of choices complete to those choices, while any
+    other option that expects a value falls back to the default completion of
+    the shell (usually, filenames).
+
+    Arguments:
+        parsers (list[ArgumentParser]): The parsers to inspect
+
+    Returns:
+        tuple[dict[str, list[str]], list[str]]: Mapping of the options with
+        their choices, and the sorted list of the other options with values
+    """
+    choices: dict[str, list[str]] = {}
+    with_values: set[str] = set()
+    for parser in parsers:
+        for action in _get_options(parser):
+            if action.choices:
+                for option in action.option_strings:
+                    choices[option] = [str(choice) for choice in action.choices]
+            elif action.nargs != 0:
+                with_values.update(action.option_strings)
+
+    return choices, sorted(with_values)
+
+
+def generate_bash_completion(parser: ArgumentParser) -> str:
+    """Generate the bash completion script for the given parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser with all the subcommands registered
+
+    Returns:
+        str: The bash completion script
+    """
+    subcommands = _get_subcommands(parser)
+    choices, with_values = _get_value_options([parser, *subcommands.values()])
+
+    lines = [
+        "# Bash completion for c. Generated by Command Line Assistant.",
+        "_c_completion() {",
+        '    local cur="${COMP_WORDS[COMP_CWORD]}"',
+        '    local prev="${COMP_WORDS[COMP_CWORD-1]}"',
+        '    local subcommand="" word opts',
+        "",
+        '    for word in "${COMP_WORDS[@]:1:COMP_CWORD-1}"; do',
+        '        case "$word" in',
+        f'            {"|".join(subcommands)}) subcommand="$word"; break ;;',
+        "        esac",
+        "    done",
+        "",
+        '    case "$prev" in',
+    ]
+    for option, values in choices.items():
+        lines.append(
+            f'        {option}) COMPREPLY=($(compgen -W "{" ".join(values)}" -- "$cur")); return ;;'
+        )
+    if with_values:
+        lines.append(f"        {'|'.join(with_values)}) return ;;")
+    lines.extend(["    esac", "", '    case "$subcommand" in'])
+    for name, subparser in subcommands.items():
+        lines.append(
+            f'        {name}) opts="{" ".join(_get_option_strings(subparser))}" ;;'
+        )
+    global_options = [*_get_option_strings(parser), *subcommands]
+    lines.extend(
+        [
+            f'        *) opts="{" ".join(global_options)}" ;;',
+            "    esac",
+            "",
+            '    COMPREPLY=($(compgen -W "$opts" -- "$cur"))',
+            "}",
+            "",
+            "complete -o default -F _c_completion c",
+            "",
+        ]
+    )
+    return "\n".join(lines)
+
+
+def generate_zsh_completion(parser: ArgumentParser) -> str:
+    """Generate the zsh completion script for the given parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser with all the subcommands registered
+
+    Returns:
+        str: The zsh completion script
+    """
+
+    def quote(value: str) -> str:
+        return "'" + value.replace("'", "'\\''") + "'"
+
+    subcommands = _get_subcommands(parser)
+    helps = _get_subcommand_helps(parser)
+    choices, with_values = _get_value_options([parser, *subcommands.values()])
+
+    lines = [
+        "#compdef c",
+        "# Zsh completion for c. Generated by Command Line Assistant.",
+        "_c() {",
+        "    local subcommand word",
+        "    local -a subcommands",
+        "    subcommands=(",
+    ]
+    for name in subcommands:
+        lines.append(f"        {quote(f'{name}:{helps.get(name, name)}')}")
+    lines.extend(
+        [
+            "    )",
+            "",
+            "    for word in ${words[2,CURRENT-1]}; do",
+            "        case $word in",
+            f"            ({'|'.join(subcommands)}) subcommand=$word; break ;;",
+            "        esac",
+            "    done",
+            "",
+            "    case ${words[CURRENT-1]} in",
+        ]
+    )
+    for option, values in choices.items():
+        lines.append(f"        ({option}) compadd -- {' '.join(values)}; return ;;")
+    if with_values:
+        lines.append(f"        ({'|'.join(with_values)}) _files; return ;;")
+    lines.extend(["    esac", "", "    case $subcommand in"])
+    for name, subparser in subcommands.items():
+        lines.append(
+            f"        ({name}) compadd -- {' '.join(_get_option_strings(subparser))} ;;"
+        )
+    lines.extend(
+        [
+            "        (*)",
+            "            _describe -t commands 'c command' subcommands",
+            f"            compadd -- {' '.join(_get_option_strings(parser))}",
+            "            ;;",
+            "    esac",
+            "}",
+            "",
+            '_c "$@"',
+            "",
+        ]
+    )
+    return "\n".join(lines)
+
+
+def generate_fish_completion(parser: ArgumentParser) -> str:
+    """Generate the fish completion script for the given parser.
+
+    Arguments:
+        parser (ArgumentParser): The parser with all the subcommands registered
+
+    Returns:
+        str: The fish completion script
+    """
+
+    def quote(value: str) -> str:
+        return "'" + value.replace("\\", "\\\\").replace("'", "\\'") + "'"
+
+    def option_line(condition: str, action: Action) -> str:
+        line = f"complete -c c -n {quote(condition)}"
+        for option in action.option_strings:
+            if option.startswith("--"):
+                line += f" -l {option[2:]}"
+            else:
+                line += f" -s {option[1:]}"
+
+        if action.choices:
+            values = " ".join(str(choice) for choice in action.choices)
+            line += f" -x -a {quote(values)}"
+        elif action.nargs != 0:
+            line += " -r -F"
+
+        description = _get_help(action)
+        if description:
+            line += f" -d {quote(description)}"
+        return line
+
+    subcommands = _get_subcommands(parser)
+    helps = _get_subcommand_helps(parser)
+
+    lines = [
+        "# Fish completion for c. Generated by Command Line Assistant.",
+        "complete -c c -f",
+    ]
+    for name in subcommands:
+        lines.append(
+            f"complete -c c -n __fish_use_subcommand -a {name} -d {quote(helps.get(name, name))}"
+        )
+    for action in _get_options(parser):
+        lines.append(option_line("__fish_use_subcommand", action))
+    for name, subparser in subcommands.items():
+        for action in _get_options(subparser):
+            lines.append(option_line(f"__fish_seen_subcommand_from {name}", action))
+
+    lines.append("")
+    return "\n".join(lines)
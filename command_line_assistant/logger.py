@@ -31,6 +31,10 @@ LOGGING_CONFIG_DICTIONARY = {
             "format": f"\x1f{DEFAULT_FORMATTER}",
             "datefmt": DEFAULT_DATE_FORMATTER,
         },
+        "json": {
+            "()": "command_line_assistant.logger.JSONFormatter",
+            "datefmt": DEFAULT_DATE_FORMATTER,
+        },
         "audit": {
             "()": "command_line_assistant.logger.AuditFormatter",
             "datefmt": DEFAULT_DATE_FORMATTER,
@@ -58,6 +62,12 @@ LOGGING_CONFIG_DICTIONARY = {
             "stream": "ext://sys.stdout",
             "filters": ["non_audit_only"],
         },
+        "systemd_json": {
+            "class": "logging.StreamHandler",
+            "formatter": "json",
+            "stream": "ext://sys.stdout",
+            "filters": ["non_audit_only"],
+        },
         "audit": {
             "class": "logging.StreamHandler",
             "formatter": "audit",
@@ -132,6 +142,36 @@ class NonAuditFilter(logging.Filter):
         return not bool(getattr(record, "audit", False))
 
 
+class JSONFormatter(logging.Formatter):
+    """Formatter that outputs every record as a single JSON line."""
+
+    def format(self, record: LogRecord) -> str:
+        """Format the record as a JSON line for log aggregation systems.
+
+        Arguments:
+            record (logging.LogRecord): The log record to format
+
+        Returns:
+            str: JSON formatted log message
+        """
+        structured_data = {
+            "timestamp": self.formatTime(record, self.datefmt),
+            "level": record.levelname,
+            "logger": record.name,
+            "message": record.getMessage(),
+            "code": {
+                "file": record.filename,
+                "line": record.lineno,
+                "function": record.funcName,
+            },
+        }
+
+        if record.exc_info:
+            structured_data["exception"] = self.formatException(record.exc_info)
+
+        return json.dumps(structured_data, default=str)
+
+
 class AuditFormatter(logging.Formatter):
     """Custom formatter that handles user-specific logging configuration."""
 
@@ -237,7 +277,7 @@ def setup_daemon_logging(config: Config) -> None:
     Arguments:
         config (Config): Instance of a config class.
     """
-    custom_handlers = ["systemd"]
+    custom_handlers = ["systemd_json" if config.logging.format == "json" else "systemd"]
     # Add audit logging in case it is enabledc
     if config.logging.audit.enabled:
         custom_handlers.append("audit")
@@ -245,11 +285,14 @@ def setup_daemon_logging(config: Config) -> None:
     _setup_logging(config.logging.level, custom_handlers)
 
 
-def setup_client_logging() -> None:
+def setup_client_logging(logging_level: str = "DEBUG") -> None:
     """Setup basic logging functionality.
 
     Note:
         This is intended to be called by the client to initialize their logging
         routine.
+
+    Arguments:
+        logging_level (str): The minimal level to log. Defaults to "DEBUG".
     """
-    _setup_logging(logging_level="DEBUG", handlers=["terminal"])
+    _setup_logging(logging_level=logging_level, handlers=["terminal"])
@@ -2,7 +2,25 @@ import itertools
 import sys
 import threading
 import time
-from typing import Optional
+from typing import Optional, TextIO
+
+
+def should_animate(stream: TextIO) -> bool:
+    """Check if the spinner should be displayed in the given stream.
+
+    The spinner is only shown when the stream is attached to a terminal, so
+    redirecting or piping the output doesn't get polluted by the animation.
+
+    Arguments:
+        stream (TextIO): The stream where the spinner would be written to.
+
+    Returns:
+        bool: True if the stream is a TTY, False otherwise.
+    """
+    try:
+        return stream.isatty()
+    except (AttributeError, ValueError):
+        return False
 
 
 class Spinner:
@@ -10,7 +28,7 @@ class Spinner:
     A spinner animation that displays a loading indicator and optional message.
     """
 
-    def __init__(self, message: str, plain: bool = False):
+    def __init__(self, message: str, plain: bool = False, enabled: bool = True):
         self._message = message
         self._frames = itertools.cycle(
             [
@@ -29,6 +47,7 @@ class Spinner:
         self._stop_event: Optional[threading.Event] = None
         self._current_line_length = 0
         self._plain = plain
+        self._enabled = enabled and should_animate(sys.stderr)
 
     def _animate(self):
         """Animation loop that updates the progress indicator with interrupt
@@ -51,7 +70,7 @@ class Spinner:
             time.sleep(0.1)
 
     def __enter__(self) -> "Spinner":
-        if not self._spinning:
+        if self._enabled and not self._spinning:
             self._stop_event = threading.Event()
             self._animation_thread = threading.Thread(target=self._animate)
             self._animation_thread.start()
@@ -4,7 +4,7 @@ ANSI color and style utilities.
 
 import os
 from enum import Enum
-from typing import Union
+from typing import TextIO, Union
 
 
 class Color(Enum):
@@ -90,6 +90,29 @@ class Style(Enum):
         return self.value
 
 
+def should_colorize(stream: TextIO, no_color: bool = False) -> bool:
+    """Decide if the output written to the stream should have colors.
+
+    Colors are disabled if the user asked for it, either through the `NO_COLOR`
+    environment variable or the `no_color` flag, or if the stream is not a
+    terminal, like when the output is redirected to a file.
+
+    Args:
+        stream (TextIO): The stream that will receive the output.
+        no_color (bool): If the user explicitly disabled the colors.
+
+    Returns:
+        bool: True if the output should be colorized, False otherwise.
+    """
+    if no_color or os.getenv("NO_COLOR"):
+        return False
+
+    try:
+        return stream.isatty()
+    except (AttributeError, ValueError):
+        return False
+
+
 def colorize(text: str, color: Union[Color, str]) -> str:
     """Colorize text with the specified color."""
     if os.getenv("NO_COLOR"):
@@ -618,6 +618,23 @@ class ANSIExtension(Extension):
 
 
 # Convenience functions
+def extract_last_code_block(text: str) -> Optional[str]:
+    """Extract the contents of the last fenced code block in the text.
+
+    Args:
+        text: The markdown text to search
+
+    Returns:
+        The code inside the last fenced block, or None if there is no block
+    """
+    blocks = FencedCodePreprocessor.FENCED_BLOCK_RE.findall(text)
+    if not blocks:
+        return None
+
+    _, code = blocks[-1]
+    return code.rstrip("\n")
+
+
 def markdown_to_ansi(text: str, theme: Optional[Theme] = None, **kwargs) -> str:
     """Convert markdown text to ANSI formatted text.
 
@@ -2,7 +2,7 @@
 
 import sys
 from datetime import datetime
-from typing import Optional
+from typing import Optional, TextIO
 
 from command_line_assistant.rendering.colors import colorize
 from command_line_assistant.rendering.stream import StreamWriter
@@ -47,16 +47,28 @@ class Renderer:
     colors and styles.
     """
 
-    def __init__(self, plain: bool = False, theme: Optional[Theme] = None):
+    def __init__(
+        self,
+        plain: bool = False,
+        theme: Optional[Theme] = None,
+        stream: Optional[TextIO] = None,
+        quiet: bool = False,
+    ):
         """Initialize render utilities.
 
         Args:
             plain (bool): Whether to use plain text rendering
             theme (Theme): Theme instance to use for colors. If None, uses
             default theme.
+            stream (Optional[TextIO]): Stream to render the non-error messages
+            to. If None, uses the standard output.
+            quiet (bool): Whether to suppress the status messages.
         """
         self._plain = plain
-        self._stream_writer: StreamWriter = StreamWriter(theme=theme)
+        self._quiet = quiet
+        self._stream_writer: StreamWriter = (
+            StreamWriter(stream, theme=theme) if stream else StreamWriter(theme=theme)
+        )
         self._error_writer: StreamWriter = StreamWriter(sys.stderr, theme=theme)
         self._theme = theme or Theme()
 
@@ -68,6 +80,18 @@ class Renderer:
         """
         self._stream_writer.write_line(message)
 
+    def status(self, message: str) -> None:
+        """Render a status message, unless the renderer is quiet.
+
+        Status messages only tell what is going on, like "Getting the
+        history", so they are left out of the output of scripts.
+
+        Args:
+            message (str): Text to render
+        """
+        if not self._quiet:
+            self._stream_writer.write_line(message)
+
     def warning(self, message: str) -> None:
         """Render a message with a yellow color.
 
@@ -0,0 +1,149 @@
+"""Utilitary module to read and write the contents of the system clipboard"""
+
+import logging
+import os
+import shutil
+import subprocess
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+#: Commands to paste the clipboard contents on Wayland sessions.
+WAYLAND_PASTE_COMMANDS: tuple[tuple[str, ...], ...] = (("wl-paste", "--no-newline"),)
+
+#: Commands to paste the clipboard contents on X11 sessions, in order of preference.
+X11_PASTE_COMMANDS: tuple[tuple[str, ...], ...] = (
+    ("xclip", "-selection", "clipboard", "-o"),
+    ("xsel", "--clipboard", "--output"),
+)
+
+#: Commands to copy text to the clipboard on Wayland sessions.
+WAYLAND_COPY_COMMANDS: tuple[tuple[str, ...], ...] = (("wl-copy",),)
+
+#: Commands to copy text to the clipboard on X11 sessions, in order of preference.
+X11_COPY_COMMANDS: tuple[tuple[str, ...], ...] = (
+    ("xclip", "-selection", "clipboard"),
+    ("xsel", "--clipboard", "--input"),
+)
+
+#: Maximum amount of seconds to wait for the paste and copy commands.
+PASTE_TIMEOUT: int = 5
+
+#: Message shown when no tool to access the clipboard is installed.
+MISSING_TOOL_MESSAGE: str = "Please install wl-clipboard, xclip or xsel."
+
+
+def _find_clipboard_command(
+    wayland_commands: tuple[tuple[str, ...], ...],
+    x11_commands: tuple[tuple[str, ...], ...],
+) -> Optional[list[str]]:
+    """Select the first available command for the current session.
+
+    Wayland tools are preferred when a Wayland session is detected, falling
+    back to the X11 ones, which also work through XWayland.
+
+    Arguments:
+        wayland_commands (tuple[tuple[str, ...], ...]): Commands for Wayland sessions
+        x11_commands (tuple[tuple[str, ...], ...]): Commands for X11 sessions
+
+    Returns:
+        Optional[list[str]]: The command to be executed, or None if no tool is
+        available for the current session.
+    """
+    candidates: list[tuple[str, ...]] = []
+    if os.environ.get("WAYLAND_DISPLAY"):
+        candidates.extend(wayland_commands)
+    if os.environ.get("DISPLAY"):
+        candidates.extend(x11_commands)
+
+    for command in candidates:
+        if shutil.which(command[0]):
+            return list(command)
+
+    return None
+
+
+def get_paste_command() -> Optional[list[str]]:
+    """Select the command to read the clipboard for the current session.
+
+    Returns:
+        Optional[list[str]]: The command to be executed, or None if no tool is
+        available for the current session.
+    """
+    command = _find_clipboard_command(WAYLAND_PASTE_COMMANDS, X11_PASTE_COMMANDS)
+    if command:
+        logger.debug("Using %s to read the clipboard.", command[0])
+    return command
+
+
+def get_copy_command() -> Optional[list[str]]:
+    """Select the command to write to the clipboard for the current session.
+
+    Returns:
+        Optional[list[str]]: The command to be executed, or None if no tool is
+        available for the current session.
+    """
+    command = _find_clipboard_command(WAYLAND_COPY_COMMANDS, X11_COPY_COMMANDS)
+    if command:
+        logger.debug("Using %s to write to the clipboard.", command[0])
+    return command
+
+
+def read_clipboard() -> str:
+    """Read the current contents of the clipboard.
+
+    Raises:
+        ValueError: In case there is no tool to read the clipboard or it fails.
+
+    Returns:
+        str: The contents of the clipboard.
+    """
+    command = get_paste_command()
+    if not command:
+        raise ValueError(
+            f"Couldn't find a tool to read the clipboard. {MISSING_TOOL_MESSAGE}"
+        )
+
+    try:
+        result = subprocess.run(
+            command, capture_output=True, text=True, check=True, timeout=PASTE_TIMEOUT
+        )
+    except (OSError, subprocess.SubprocessError) as e:
+        logger.debug("Failed to read the clipboard with %s: %s", command[0], e)
+        raise ValueError(f"Couldn't read the clipboard using '{command[0]}'.") from e
+
+    return result.stdout
+
+
+def write_clipboard(text: str) -> None:
+    """Replace the contents of the clipboard with the given text.
+
+    Arguments:
+        text (str): The text to copy to the clipboard.
+
+    Raises:
+        ValueError: In case there is no tool to write the clipboard or it fails.
+    """
+    command = get_copy_command()
+    if not command:
+        raise ValueError(
+            f"Couldn't find a tool to write to the clipboard. {MISSING_TOOL_MESSAGE}"
+        )
+
+    # The copy tools keep running in the background to serve the clipboard
+    # contents, so their output can't be captured without waiting on them.
+    try:
+        subprocess.run(
+            command,
+            input=text,
+            stdout=subprocess.DEVNULL,
+            stderr=subprocess.DEVNULL,
+            text=True,
+            check=True,
+            timeout=PASTE_TIMEOUT,
+        )
+    except (OSError, subprocess.SubprocessError) as e:
+        logger.debug("Failed to write the clipboard with %s: %s", command[0], e)
+        raise ValueError(
+            f"Couldn't write to the clipboard using '{command[0]}'."
+        ) from e
@@ -0,0 +1,76 @@
+"""Utilitary module to render JSON templates with placeholders"""
+
+import json
+import re
+from collections.abc import Iterable
+from typing import Any
+
+#: Match placeholders like `${question}` or `${system_info.os}`.
+PLACEHOLDER_PATTERN = re.compile(r"\$\{([a-z_][a-z0-9_.]*)\}")
+
+
+def _parse_json_template(template: str) -> dict[str, Any]:
+    """Parse the template as a JSON object.
+
+    Arguments:
+        template (str): The JSON template
+
+    Raises:
+        ValueError: In case the template is not a valid JSON object.
+
+    Returns:
+        dict[str, Any]: The parsed template
+    """
+    try:
+        parsed = json.loads(template)
+    except json.JSONDecodeError as e:
+        raise ValueError(f"The template is not valid JSON: {e}") from e
+
+    if not isinstance(parsed, dict):
+        raise ValueError("The template must be a JSON object.")
+
+    return parsed
+
+
+def validate_json_template(template: str, variables: Iterable[str]) -> None:
+    """Check that the template is a JSON object using only known placeholders.
+
+    Arguments:
+        template (str): The JSON template
+        variables (Iterable[str]): The placeholders allowed in the template
+
+    Raises:
+        ValueError: In case the template is invalid or has unknown placeholders.
+    """
+    _parse_json_template(template)
+
+    unknown = sorted(set(PLACEHOLDER_PATTERN.findall(template)) - set(variables))
+    if unknown:
+        raise ValueError(
+            f"The template has unknown placeholders: {', '.join(unknown)}. Available placeholders are: {', '.join(variables)}."
+        )
+
+
+def render_json_template(template: str, variables: dict[str, str]) -> dict[str, Any]:
+    """Render a JSON template, replacing the placeholders in its string values.
+
+    Arguments:
+        template (str): The JSON template
+        variables (dict[str, str]): The values for each placeholder
+
+    Returns:
+        dict[str, Any]: The rendered template
+    """
+
+    def _render(value: Any) -> Any:
+        if isinstance(value, str):
+            return PLACEHOLDER_PATTERN.sub(
+                lambda match: variables.get(match.group(1), ""), value
+            )
+        if isinstance(value, dict):
+            return {key: _render(item) for key, item in value.items()}
+        if isinstance(value, list):
+            return [_render(item) for item in value]
+        return value
+
+    return _render(_parse_json_template(template))
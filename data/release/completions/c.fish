@@ -0,0 +1,51 @@
+# Fish completion for c. Generated by Command Line Assistant.
+complete -c c -f
+complete -c c -n __fish_use_subcommand -a chat -d 'Command to ask a question to the LLM'
+complete -c c -n __fish_use_subcommand -a feedback -d 'Submit feedback about the Command Line Assistant responses and interactions.'
+complete -c c -n __fish_use_subcommand -a history -d 'Manage Conversation History'
+complete -c c -n __fish_use_subcommand -a shell -d 'Manage shell integrations'
+complete -c c -n '__fish_use_subcommand' -l debug -d 'Enable debug logging information'
+complete -c c -n '__fish_use_subcommand' -l log-level -x -a 'CRITICAL ERROR WARNING INFO DEBUG' -d 'Enable logging information from the given level. --debug is the same as --log-level DEBUG.'
+complete -c c -n '__fish_use_subcommand' -s h -l help -d 'Show help message and exit.'
+complete -c c -n '__fish_use_subcommand' -s v -l version -d 'Show program version'
+complete -c c -n '__fish_use_subcommand' -s p -l plain -d 'Enable plain output. This will disable colors, animations, and other rich content.'
+complete -c c -n '__fish_use_subcommand' -l no-color -d 'Disable colors in the output. Also respects the NO_COLOR environment variable.'
+complete -c c -n '__fish_use_subcommand' -s q -l quiet -d 'Suppress the status messages, keeping only the results and errors.'
+complete -c c -n '__fish_seen_subcommand_from chat' -s h -l help -d 'show this help message and exit'
+complete -c c -n '__fish_seen_subcommand_from chat' -s a -l attachment -r -F -d 'File attachment to be read and sent alongside the query'
+complete -c c -n '__fish_seen_subcommand_from chat' -l from-clipboard -d 'Use the contents of the clipboard as the question.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l copy -d 'Copy the last code block of the answer to the clipboard.'
+complete -c c -n '__fish_seen_subcommand_from chat' -s i -l interactive -d 'Start an interactive chat session'
+complete -c c -n '__fish_seen_subcommand_from chat' -s w -l with-output -r -F -d 'Add output from terminal as context for the query. Use 1 to retrieve the latest output, 2 to and so on. First, enable the terminal capture with \'c shell --enable-capture\' for this option to work.'
+complete -c c -n '__fish_seen_subcommand_from chat' -s l -l list -d 'List all chats'
+complete -c c -n '__fish_seen_subcommand_from chat' -s d -l delete -r -F -d 'Delete a chat session. Specify the chat session by its name.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l delete-all -d 'Delete all chats'
+complete -c c -n '__fish_seen_subcommand_from chat' -s n -l name -r -F -d 'Give a name to the chat session.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l description -r -F -d 'Give a description to the chat session.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l timeout -r -F -d 'Maximum amount of seconds to wait for an answer. By default, there is no limit.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l no-progress -d 'Don\'t show the progress indicator while waiting for an answer.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l dry-run -d 'Print the question that would be sent, with all of its context, without sending it.'
+complete -c c -n '__fish_seen_subcommand_from chat' -l output -x -a 'text json' -d 'The format of the answer for a single question. With json, the question and the answer are printed as a JSON object and any other message goes to the standard error.'
+complete -c c -n '__fish_seen_subcommand_from feedback' -s h -l help -d 'show this help message and exit'
+complete -c c -n '__fish_seen_subcommand_from feedback' -l submit -d 'Submit feedback (default action)'
+complete -c c -n '__fish_seen_subcommand_from history' -s h -l help -d 'show this help message and exit'
+complete -c c -n '__fish_seen_subcommand_from history' -l from-chat -r -F -d 'Specify from which chat we should retrieve the history. Default chat is \'default\''
+complete -c c -n '__fish_seen_subcommand_from history' -s f -l first -d 'Get the first conversation from history.'
+complete -c c -n '__fish_seen_subcommand_from history' -s l -l last -d 'Get the last conversation from history.'
+complete -c c -n '__fish_seen_subcommand_from history' -l filter -r -F -d 'Search for a specific keyword of text in the history.'
+complete -c c -n '__fish_seen_subcommand_from history' -s a -l all -d 'Get all the conversation history.'
+complete -c c -n '__fish_seen_subcommand_from history' -s c -l clear -d 'Clear the entire history for a given chat. Use --from-chat with its given name to clear that particular history.'
+complete -c c -n '__fish_seen_subcommand_from history' -l clear-all -d 'Clear the entire history.'
+complete -c c -n '__fish_seen_subcommand_from history' -l stats -d 'Show a summary of the conversation history, like the number of interactions.'
+complete -c c -n '__fish_seen_subcommand_from shell' -s h -l help -d 'show this help message and exit'
+complete -c c -n '__fish_seen_subcommand_from shell' -l enable-capture -d 'Enable terminal capture for the current terminal session.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l enable-interactive -d 'Enable the shell integration for interactive mode on the system. Currently, only BASH is supported. After the interactive was sourced, hit Ctrl + G in your terminal to enable interactive mode.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l disable-interactive -d 'Disable the shell integration for interactive mode on the system.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l enable-completion -d 'Install the completion script for the c command. The shell is detected automatically if --shell-type is not given.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l disable-completion -d 'Remove the completion script for the c command. If --shell-type is not given, the completion is removed for all supported shells.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l print-completion -d 'Print the completion script for the c command instead of installing it. The shell is detected automatically if --shell-type is not given.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l print-all-completions -d 'Print the completion scripts for all supported shells, each one preceded by a header with the shell name and its install location.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l install-alias -d 'Add a \'??\' alias to the rc file of your shell, so \'c chat\' can be called as \'?? question\'. The shell is detected automatically if --shell-type is not given.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l uninstall-alias -d 'Remove the \'??\' alias from the rc file of your shell. The shell is detected automatically if --shell-type is not given.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l status -d 'Show whether the completion for the c command is installed. If --shell-type is not given, all supported shells are checked.'
+complete -c c -n '__fish_seen_subcommand_from shell' -l shell-type -x -a 'bash zsh fish' -d 'The shell to manage the integration for.'
@@ -114,6 +114,11 @@ ln -sr %{buildroot}%{_mandir}/man1/%{binary_name}.1 %{buildroot}%{_mandir}/man1/
 %{__install} -D -m 0644 data/release/man/%{binary_name}.1 %{buildroot}/%{_mandir}/man1/%{binary_name}.1
 %{__install} -D -m 0644 data/release/man/%{daemon_binary_name}.8 %{buildroot}/%{_mandir}/man8/%{daemon_binary_name}.8
 
+# Shell completions
+%{__install} -D -m 0644 data/release/completions/%{binary_name} %{buildroot}/%{_datadir}/bash-completion/completions/%{binary_name}
+%{__install} -D -m 0644 data/release/completions/_%{binary_name} %{buildroot}/%{_datadir}/zsh/site-functions/_%{binary_name}
+%{__install} -D -m 0644 data/release/completions/%{binary_name}.fish %{buildroot}/%{_datadir}/fish/vendor_completions.d/%{binary_name}.fish
+
 # selinux
 %{__install} -m 0644 data/release/selinux/%{modulename}.pp.bz2 %{buildroot}%{_datadir}/selinux/packages/%{selinuxtype}/%{modulename}.pp.bz2
 
@@ -166,6 +171,11 @@ fi
 %{_mandir}/man1/%{symlink_binary_name}.1.gz
 %{_mandir}/man8/%{daemon_binary_name}.8.gz
 
+# Shell completions
+%{_datadir}/bash-completion/completions/%{binary_name}
+%{_datadir}/zsh/site-functions/_%{binary_name}
+%{_datadir}/fish/vendor_completions.d/%{binary_name}.fish
+
 
 # Needed directories
 %dir %attr(0700, root, root) %{_sharedstatedir}/%{name}
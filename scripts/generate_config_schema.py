@@ -0,0 +1,158 @@
+"""Generate a JSON Schema for the clad config.toml file.
+
+The schema is derived from the dataclasses under
+`command_line_assistant.config`, so editors can validate the configuration
+file before clad complains about it at runtime.
+
+Usage:
+    python scripts/generate_config_schema.py [output]
+"""
+
+import dataclasses
+import json
+import re
+import sys
+import typing
+from pathlib import Path
+from typing import Any, Union
+
+from command_line_assistant.config import Config
+from command_line_assistant.config.schemas.database import ALLOWED_DATABASES
+
+#: Default location of the generated schema.
+DEFAULT_OUTPUT: Path = Path("schemas/config.schema.json")
+
+#: Fields that are still accepted, but don't have any effect anymore.
+DEPRECATED_FIELDS: set[str] = {"AuthSchema.verify_ssl"}
+
+#: Fields that only accept a fixed set of values.
+ENUM_FIELDS: dict[str, tuple[str, ...]] = {"DatabaseSchema.type": ALLOWED_DATABASES}
+
+#: Sections that must be present in the config file for clad to start.
+REQUIRED_SECTIONS: list[str] = ["database", "history", "backend", "logging"]
+
+JSON_TYPES: dict[Any, str] = {
+    bool: "boolean",
+    int: "integer",
+    float: "number",
+    str: "string",
+    Path: "string",
+}
+
+
+def _get_descriptions(schema: type) -> dict[str, str]:
+    """Parse the `Attributes:` section of a dataclass docstring."""
+    _, _, attributes = (schema.__doc__ or "").partition("Attributes:")
+    descriptions: dict[str, str] = {}
+    name = ""
+    for line in attributes.splitlines():
+        match = re.match(r"^\s+(\w+) \([^)]*\):\s*(.+)$", line)
+        if match:
+            name = match.group(1)
+            descriptions[name] = match.group(2)
+        elif name and line.strip():
+            # Descriptions that don't fit in a single line.
+            descriptions[name] += f" {line.strip()}"
+
+    return descriptions
+
+
+def _get_type_schema(hint: Any) -> dict[str, Any]:
+    """Convert a type hint into the matching JSON Schema."""
+    if dataclasses.is_dataclass(hint):
+        return _get_object_schema(hint)  # type: ignore[arg-type]
+
+    origin = typing.get_origin(hint)
+    if origin is Union:
+        # TOML has no null value, so optional fields are just left out.
+        types = [arg for arg in typing.get_args(hint) if arg is not type(None)]
+        schemas = [_get_type_schema(arg) for arg in types]
+        unique = []
+        for schema in schemas:
+            if schema not in unique:
+                unique.append(schema)
+        return unique[0] if len(unique) == 1 else {"anyOf": unique}
+
+    if origin is list:
+        (value,) = typing.get_args(hint)
+        return {"type": "array", "items": _get_type_schema(value)}
+
+    if origin is dict:
+        _, value = typing.get_args(hint)
+        return {"type": "object", "additionalProperties": _get_type_schema(value)}
+
+    return {"type": JSON_TYPES[hint]}
+
+
+def _get_default(field: dataclasses.Field) -> Any:
+    """Retrieve the default value of a field in a JSON friendly format."""
+    if field.default is not dataclasses.MISSING:
+        default = field.default
+    elif field.default_factory is not dataclasses.MISSING:
+        default = field.default_factory()
+    else:
+        return None
+
+    if dataclasses.is_dataclass(default) or default is None:
+        return None
+
+    return str(default) if isinstance(default, Path) else default
+
+
+def _get_object_schema(schema: type) -> dict[str, Any]:
+    """Generate the JSON Schema of a config section."""
+    hints = typing.get_type_hints(schema)
+    descriptions = _get_descriptions(schema)
+    properties = {}
+
+    for field in dataclasses.fields(schema):
+        name = f"{schema.__name__}.{field.name}"
+        prop = _get_type_schema(hints[field.name])
+
+        if dataclasses.is_dataclass(hints[field.name]):
+            prop["description"] = hints[field.name].__doc__.strip().splitlines()[0]
+        elif field.name in descriptions:
+            prop["description"] = descriptions[field.name]
+        if name in ENUM_FIELDS:
+            prop["enum"] = list(ENUM_FIELDS[name])
+        if name in DEPRECATED_FIELDS:
+            prop["deprecated"] = True
+
+        default = _get_default(field)
+        if default is not None:
+            prop["default"] = default
+
+        properties[field.name] = prop
+
+    return {
+        "type": "object",
+        "additionalProperties": False,
+        "properties": properties,
+    }
+
+
+def generate_schema() -> dict[str, Any]:
+    """Generate the JSON Schema for the whole config file."""
+    schema = _get_object_schema(Config)
+    # Unknown sections are ignored by clad, so they are allowed here as well.
+    del schema["additionalProperties"]
+    return {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "https://raw.githubusercontent.com/rhel-lightspeed/command-line-assistant/main/schemas/config.schema.json",
+        "title": "Configuration file for clad",
+        "description": "This schema defines the structure of the config.toml file read by clad.",
+        **schema,
+        "required": REQUIRED_SECTIONS,
+    }
+
+
+def main() -> int:
+    """Write the generated schema to the given path."""
+    output = Path(sys.argv[1]) if len(sys.argv) > 1 else DEFAULT_OUTPUT
+    output.write_text(json.dumps(generate_schema(), indent=2) + "\n")
+    print(f"Config schema written to {output}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -1,3 +1,4 @@
+import json
 import os
 from argparse import Namespace
 from datetime import datetime
@@ -5,6 +6,8 @@ from unittest import mock
 from unittest.mock import patch
 
 import pytest
+from dasbus.error import DBusError
+from gi.repository import Gio, GLib
 
 from command_line_assistant.commands import chat
 from command_line_assistant.commands.chat import (
@@ -15,6 +18,7 @@ from command_line_assistant.dbus.client import DbusClient
 from command_line_assistant.dbus.exceptions import (
     ChatNotFoundError,
     HistoryNotEnabledError,
+    RequestFailedError,
 )
 from command_line_assistant.dbus.structures.chat import (
     ChatEntry,
@@ -41,6 +45,13 @@ def default_namespace() -> Namespace:
         description="",
         with_output=None,
         plain=True,
+        quiet=False,
+        output="text",
+        timeout=None,
+        no_progress=False,
+        dry_run=False,
+        from_clipboard=False,
+        copy=False,
     )
 
 
@@ -100,6 +111,26 @@ def test_single_question_invalid_query(default_namespace, command_context):
         )
 
 
+@pytest.mark.parametrize(
+    ("attribute", "value"),
+    (
+        ("query_string", " "),
+        ("timeout", 0),
+    ),
+)
+def test_chat_command_usage_error(
+    attribute, value, default_namespace, command_context, mock_dbus_service
+):
+    """Validation failures are reported as a usage error."""
+    default_namespace.query_string = "test question"
+    setattr(default_namespace, attribute, value)
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    assert result == os.EX_USAGE
+    mock_dbus_service.AskQuestion.assert_not_called()
+
+
 def test_single_question_value_error(default_namespace, command_context, monkeypatch):
     default_namespace.query_string = "ate"
     mock_func = mock.MagicMock()
@@ -226,6 +257,32 @@ def test_interactive_mode(mock_dbus_service, default_namespace, command_context)
         assert result == 0
 
 
+@pytest.mark.parametrize(
+    ("lines", "expected"),
+    (
+        (["how do I list files?"], "how do I list files?"),
+        (["  padded question  "], "padded question"),
+        (["first line \\", "second line"], "first line \nsecond line"),
+        (["first \\", "second \\", "third"], "first \nsecond \nthird"),
+        (["first \\", "second \\", ".send"], "first \nsecond"),
+        ([".send"], ""),
+        ([""], ""),
+        ([".exit"], None),
+        (["first \\", " .exit "], None),
+        (["first \\", EOFError], None),
+        ([EOFError], None),
+    ),
+)
+def test_read_interactive_question(lines, expected):
+    with patch("builtins.input") as mock_input:
+        mock_input.side_effect = lines
+
+        assert chat._read_interactive_question() == expected
+
+    prompts = [call.args[0] for call in mock_input.call_args_list]
+    assert prompts == [">>> "] + ["... "] * (len(lines) - 1)
+
+
 def test_interactive_mode_empty_question(
     default_namespace, command_context, capsys, disable_stream_flush
 ):
@@ -301,6 +358,25 @@ def test_validation_errors(query_string, expected_error, default_namespace):
     assert expected_error in result
 
 
+def test_from_clipboard_with_query_validation(default_namespace):
+    default_namespace.query_string = "test question"
+    default_namespace.from_clipboard = True
+    assert (
+        chat._validate_query_composition(default_namespace)
+        == "The option '--from-clipboard' can't be used together with a query."
+    )
+
+
+@pytest.mark.parametrize("timeout", (0, -10))
+def test_timeout_validation(timeout, default_namespace):
+    default_namespace.query_string = "test question"
+    default_namespace.timeout = timeout
+    assert (
+        chat._validate_query_composition(default_namespace)
+        == "The timeout needs to be a positive number of seconds."
+    )
+
+
 def test_stdin_validation(default_namespace):
     """Test stdin validation."""
     default_namespace.stdin = "a"  # Too short
@@ -347,8 +423,8 @@ def test_chat_command_with_terminal_output(
 ):
     """Test parsing with_output in chat_command.
 
-    This test will return 80 (error), but we don't care of the rest of the flow
-    execution.
+    This test will return 64 (usage error), as the terminal capture is not
+    active, but we don't care of the rest of the flow execution.
     """
     mock_dbus_service.GetUserId.return_value = "test-user"
     mock_dbus_service.GetChatId.side_effect = ChatNotFoundError("No chat found")
@@ -360,7 +436,7 @@ def test_chat_command_with_terminal_output(
     # No name or description provided
     result = chat.chat_command.func(default_namespace, command_context)
 
-    assert result == 80
+    assert result == os.EX_USAGE
 
 
 def test_chat_command_name_without_description(
@@ -418,6 +494,210 @@ def test_chat_command_exception(
     assert "failed" in captured.out
 
 
+def test_chat_command_exception_json_output(
+    mock_dbus_service, default_namespace, command_context, capsys, monkeypatch
+):
+    mock_func = mock.MagicMock()
+    mock_func.side_effect = ChatCommandException("failed")
+    monkeypatch.setattr(chat, "_single_question", mock_func)
+    default_namespace.query_string = "test question"
+    default_namespace.output = "json"
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 80
+    assert json.loads(captured.out) == {"error": "failed", "exit_code": 80}
+
+
+def test_single_question_json_output(
+    mock_dbus_service, default_namespace, command_context, capsys, tmp_path, monkeypatch
+):
+    monkeypatch.setattr(chat, "get_xdg_state_path", lambda: tmp_path)
+    mock_dbus_service.AskQuestion.return_value = Response("test response").structure()
+    default_namespace.query_string = "test question"
+    default_namespace.output = "json"
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert json.loads(captured.out) == {
+        "query": "test question",
+        "response": "test response",
+        "exit_code": 0,
+    }
+    assert "This feature uses AI technology." in captured.err
+
+
+@pytest.mark.parametrize(
+    ("side_effect", "expected"),
+    (
+        (
+            GLib.Error.new_literal(
+                Gio.io_error_quark(), "Timeout was reached", Gio.IOErrorEnum.TIMED_OUT
+            ),
+            {
+                "error": "Didn't receive an answer within 5 seconds. Please try again or increase the --timeout value.",
+                "exit_code": os.EX_UNAVAILABLE,
+            },
+        ),
+        (
+            RequestFailedError("Communication error with the server."),
+            {
+                "error": "Communication error with the server.",
+                "exit_code": os.EX_UNAVAILABLE,
+            },
+        ),
+    ),
+)
+def test_single_question_json_output_error(
+    mock_dbus_service,
+    default_namespace,
+    command_context,
+    capsys,
+    tmp_path,
+    monkeypatch,
+    side_effect,
+    expected,
+):
+    monkeypatch.setattr(chat, "get_xdg_state_path", lambda: tmp_path)
+    mock_dbus_service.AskQuestion.side_effect = side_effect
+    default_namespace.query_string = "test question"
+    default_namespace.output = "json"
+    default_namespace.timeout = 5
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == os.EX_UNAVAILABLE
+    assert json.loads(captured.out) == expected
+
+
+def test_single_question_json_output_invalid_input(
+    mock_dbus_service, default_namespace, command_context, capsys, monkeypatch
+):
+    monkeypatch.setattr(
+        chat, "_gather_input_sources", mock.Mock(side_effect=ValueError("Empty."))
+    )
+    default_namespace.query_string = "test question"
+    default_namespace.output = "json"
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == os.EX_DATAERR
+    assert json.loads(captured.out) == {"error": "Empty.", "exit_code": os.EX_DATAERR}
+
+
+def test_single_question_errors_without_json_output(
+    mock_dbus_service, default_namespace, command_context
+):
+    mock_dbus_service.AskQuestion.side_effect = RequestFailedError("failed")
+    default_namespace.query_string = "test question"
+
+    with pytest.raises(RequestFailedError, match="failed"):
+        chat.chat_command.func(default_namespace, command_context)
+
+
+def test_chat_command_json_output_interactive(
+    mock_dbus_service, default_namespace, command_context, capsys
+):
+    default_namespace.interactive = True
+    default_namespace.output = "json"
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == os.EX_USAGE
+    assert json.loads(captured.out) == {
+        "error": "The option '--output json' can't be used together with '--interactive'.",
+        "exit_code": os.EX_USAGE,
+    }
+    mock_dbus_service.GetUserId.assert_not_called()
+
+
+def test_single_question_dry_run(
+    mock_dbus_service, default_namespace, command_context, capsys
+):
+    default_namespace.query_string = "test question"
+    default_namespace.stdin = "test stdin"
+    default_namespace.dry_run = True
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    output = json.loads(captured.out)
+    assert output["message"] == "test question test stdin"
+    assert output["stdin"] == "test stdin"
+    assert "os" in output["systeminfo"]
+    mock_dbus_service.CreateChat.assert_not_called()
+    mock_dbus_service.AskQuestion.assert_not_called()
+
+
+def test_single_question_dry_run_without_daemon(
+    mock_dbus_service, default_namespace, command_context, capsys
+):
+    mock_dbus_service.GetUserId.side_effect = DBusError("clad is not running")
+    default_namespace.query_string = "test question"
+    default_namespace.dry_run = True
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert json.loads(captured.out)["message"] == "test question"
+    mock_dbus_service.GetUserId.assert_not_called()
+
+
+def test_single_question_copy(
+    mock_dbus_service,
+    default_namespace,
+    command_context,
+    capsys,
+    tmp_path,
+    monkeypatch,
+    disable_stream_flush,
+):
+    monkeypatch.setattr(chat, "get_xdg_state_path", lambda: tmp_path)
+    mock_write = mock.Mock()
+    monkeypatch.setattr(chat, "write_clipboard", mock_write)
+    mock_dbus_service.AskQuestion.return_value = Response(
+        "Run:\n```bash\nls -la\n```"
+    ).structure()
+    default_namespace.query_string = "test question"
+    default_namespace.copy = True
+
+    result = chat.chat_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    mock_write.assert_called_once_with("ls -la")
+    assert "Copied to the clipboard:\nls -la" in captured.out
+
+
+@pytest.mark.parametrize(
+    ("response", "side_effect", "expected"),
+    (
+        ("No code here.", None, "The answer has no code block to copy."),
+        (
+            "```\nls\n```",
+            ValueError("Couldn't find a tool to write to the clipboard."),
+            "Couldn't find a tool to write to the clipboard.",
+        ),
+    ),
+)
+def test_copy_code_block_warning(
+    response, side_effect, expected, capsys, monkeypatch, disable_stream_flush
+):
+    monkeypatch.setattr(chat, "write_clipboard", mock.Mock(side_effect=side_effect))
+
+    chat._copy_code_block(Renderer(plain=True), response)
+
+    captured = capsys.readouterr()
+    assert expected in captured.out
+
+
 def test_parse_attachment_file_success(tmp_path):
     """Test parsing attachment file successfully."""
     file_path = tmp_path / "test.txt"
@@ -551,6 +831,58 @@ def test_submit_question_history_disabled(mock_dbus_service, caplog):
     assert "The history is disabled in the configuration file" in caplog.text
 
 
+@pytest.mark.parametrize(("progress",), ((True,), (False,)))
+def test_submit_question_progress(mock_dbus_service, progress):
+    mock_dbus_service.AskQuestion.return_value = Response("test response").structure()
+    message_input = Question(message="test question")
+
+    with patch("command_line_assistant.commands.chat.Spinner") as mock_spinner:
+        chat._submit_question(
+            dbus=DbusClient(),
+            user_id="test-user",
+            chat_id="test-chat",
+            message_input=message_input,
+            plain=True,
+            progress=progress,
+        )
+
+    mock_spinner.assert_called_once_with(
+        message="Asking RHEL Lightspeed", plain=True, enabled=progress
+    )
+
+
+def test_get_response_with_timeout(mock_dbus_service):
+    mock_dbus_service.AskQuestion.return_value = Response("test response").structure()
+    message_input = Question(message="test question")
+
+    result = chat._get_response(DbusClient(), message_input, "test-user", timeout=5)
+
+    assert result == "test response"
+    mock_dbus_service.AskQuestion.assert_called_once_with(
+        "test-user", message_input.structure(), timeout=5000
+    )
+
+
+def test_get_response_timed_out(mock_dbus_service):
+    mock_dbus_service.AskQuestion.side_effect = GLib.Error.new_literal(
+        Gio.io_error_quark(), "Timeout was reached", Gio.IOErrorEnum.TIMED_OUT
+    )
+
+    with pytest.raises(TimeoutError, match="Didn't receive an answer within 5 seconds"):
+        chat._get_response(
+            DbusClient(), Question(message="test question"), "test-user", timeout=5
+        )
+
+
+def test_get_response_other_glib_error(mock_dbus_service):
+    mock_dbus_service.AskQuestion.side_effect = GLib.Error("Something else")
+
+    with pytest.raises(GLib.Error, match="Something else"):
+        chat._get_response(
+            DbusClient(), Question(message="test question"), "test-user", timeout=5
+        )
+
+
 def test_create_chat_session_existing(mock_dbus_service):
     """Test creating chat session when one already exists."""
 
@@ -726,3 +1058,19 @@ def test_gather_input_sources(default_namespace, monkeypatch):
     assert result.attachment == "test"
     assert result.attachment_mimetype == "test"
     assert result.terminal_output == "test"
+
+
+def test_gather_input_sources_from_clipboard(default_namespace, monkeypatch):
+    default_namespace.from_clipboard = True
+    monkeypatch.setattr(chat, "read_clipboard", lambda: "  how to list files?\n")
+
+    result = chat._gather_input_sources(default_namespace)
+    assert result.question == "how to list files?"
+
+
+def test_gather_input_sources_empty_clipboard(default_namespace, monkeypatch):
+    default_namespace.from_clipboard = True
+    monkeypatch.setattr(chat, "read_clipboard", lambda: "  \n")
+
+    with pytest.raises(ValueError, match="The clipboard is empty"):
+        chat._gather_input_sources(default_namespace)
@@ -118,6 +118,54 @@ def test_create_argument_parser():
         (["/usr/bin/c", "test query"], None, ["chat", "test query"]),
         (["/usr/bin/c", "history"], None, ["history"]),
         (["/usr/bin/c", "shell"], None, ["shell"]),
+        # The sentinel forces everything after it to be a chat question.
+        (["c", "shell", "--status"], None, ["shell", "--status"]),
+        (["c", "--", "shell", "--status"], None, ["chat", "--", "shell --status"]),
+        (["c", "--", "history is broken"], None, ["chat", "--", "history is broken"]),
+        (
+            ["c", "--plain", "--", "shell", "is", "broken"],
+            None,
+            ["--plain", "chat", "--", "shell is broken"],
+        ),
+        (
+            ["c", "-a", "file.txt", "--", "history"],
+            None,
+            ["chat", "-a", "file.txt", "--", "history"],
+        ),
+        (
+            ["c", "chat", "--", "shell", "--status"],
+            None,
+            ["chat", "--", "shell --status"],
+        ),
+        (["c", "--"], "query from stdin", ["chat"]),
+        # Other subcommands keep the sentinel for argparse.
+        (["c", "history", "--", "foo"], None, ["history", "--", "foo"]),
+        (
+            ["c", "--plain", "shell", "--", "--status"],
+            None,
+            ["--plain", "shell", "--", "--status"],
+        ),
+        (["c", "--no-color", "test query"], None, ["--no-color", "chat", "test query"]),
+        (
+            ["c", "--log-level", "info", "test query"],
+            None,
+            ["--log-level", "info", "chat", "test query"],
+        ),
+        (
+            ["c", "--log-level=info", "test query"],
+            None,
+            ["--log-level=info", "chat", "test query"],
+        ),
+        (
+            ["c", "--log-level", "info", "--", "shell"],
+            None,
+            ["--log-level", "info", "chat", "--", "shell"],
+        ),
+        (
+            ["c", "--log-level", "info", "history"],
+            None,
+            ["--log-level", "info", "history"],
+        ),
     ],
 )
 def test_add_default_command(args, stdin, expected):
@@ -23,10 +23,12 @@ def default_namespace():
         last=False,
         clear=False,
         clear_all=False,
+        stats=False,
         filter=None,
         all=False,
         from_chat="default",
         plain=True,
+        quiet=False,
     )
 
 
@@ -301,6 +303,28 @@ def test_history_command_plain_mode(
     assert "test query" in captured.out
 
 
+def test_history_command_quiet(
+    mock_dbus_service,
+    default_namespace,
+    sample_history_entry,
+    command_context,
+    capsys,
+    disable_stream_flush,
+):
+    """Test that the quiet mode only outputs the history entries."""
+    mock_dbus_service.GetUserId.return_value = "test-user"
+    mock_dbus_service.GetHistory.return_value = sample_history_entry.structure()
+
+    default_namespace.quiet = True
+    result = history.history_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert "Getting all conversations from history" not in captured.out
+    assert "test query" in captured.out
+    assert "test response" in captured.out
+
+
 def test_history_command_multiple_entries(capsys, disable_stream_flush):
     """Test history command with multiple entries."""
     entries = HistoryList(
@@ -360,3 +384,54 @@ def test_history_operations_with_history_disabled(
     captured = capsys.readouterr()
     assert result == 82
     assert exception_msg in captured.out
+
+
+def test_history_command_stats(mock_dbus_service, capsys, disable_stream_flush):
+    """Test summarizing the history across every chat."""
+    mock_dbus_service.GetUserId.return_value = "test-user"
+    mock_dbus_service.GetHistory.return_value = HistoryList(
+        [
+            HistoryEntry("q1", "abcd", "default", "2025-01-02 10:00:00.000000"),
+            HistoryEntry("q2", "ab", "other", "2025-01-01 09:00:00.000000"),
+            HistoryEntry("q3", "abcdef", "default", "2025-01-03 11:00:00.000000"),
+        ]
+    ).structure()
+
+    result = history._history_stats(Renderer(plain=True), DbusClient(), "test-user")
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert "Interactions: 3" in captured.out
+    assert "Chats: 2" in captured.out
+    assert "First entry: Wednesday, January 01, 2025 at 09:00:00 AM" in captured.out
+    assert "Last entry: Friday, January 03, 2025 at 11:00:00 AM" in captured.out
+    assert "Average response length: 4 characters" in captured.out
+
+
+def test_history_command_stats_empty(mock_dbus_service, capsys, disable_stream_flush):
+    """Test summarizing an empty history."""
+    mock_dbus_service.GetUserId.return_value = "test-user"
+    mock_dbus_service.GetHistory.side_effect = HistoryNotAvailableError(
+        "Looks like no history was found. Try asking something first!"
+    )
+
+    result = history._history_stats(Renderer(plain=True), DbusClient(), "test-user")
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert "Interactions: 0" in captured.out
+    assert "First entry: -" in captured.out
+    assert "Average response length: 0 characters" in captured.out
+
+
+def test_history_command_stats_not_enabled(mock_dbus_service):
+    """Test summarizing the history when it is not enabled."""
+    mock_dbus_service.GetUserId.return_value = "test-user"
+    mock_dbus_service.GetHistory.side_effect = HistoryNotEnabledError(
+        "Looks like history is not enabled yet"
+    )
+
+    with pytest.raises(
+        HistoryCommandException, match="Looks like history is not enabled yet"
+    ):
+        history._history_stats(Renderer(True), DbusClient(), "test-user")
@@ -1,4 +1,7 @@
+import shutil
+import subprocess
 from argparse import Namespace
+from pathlib import Path
 from unittest import mock
 
 import pytest
@@ -6,6 +9,12 @@ import pytest
 from command_line_assistant.commands import shell
 from command_line_assistant.commands.cli import CommandContext
 from command_line_assistant.exceptions import ShellCommandException
+from command_line_assistant.integrations import (
+    generate_alias,
+    generate_bash_interactive,
+    quote_fish_value,
+    quote_shell_value,
+)
 from command_line_assistant.utils.files import NamedFileLock
 
 
@@ -15,7 +24,16 @@ def default_namespace():
         enable_capture=False,
         enable_interactive=False,
         disable_interactive=False,
+        enable_completion=False,
+        disable_completion=False,
+        print_completion=False,
+        print_all_completions=False,
+        status=False,
+        install_alias=False,
+        uninstall_alias=False,
+        shell_type=None,
         plain=True,
+        quiet=False,
     )
 
 
@@ -56,6 +74,29 @@ def mock_bash_rc(monkeypatch, tmp_path):
         interactive_mode_integration_file,
     )
 
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.BASH_COMPLETION_FILE",
+        tmp_path / "bash-completion" / "completions" / "c",
+    )
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.ZSH_COMPLETION_FILE",
+        tmp_path / "zsh" / "site-functions" / "_c",
+    )
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.FISH_COMPLETION_FILE",
+        tmp_path / "fish" / "completions" / "c.fish",
+    )
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.BASH_RC_FILE", tmp_path / ".bashrc"
+    )
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.ZSH_RC_FILE", tmp_path / ".zshrc"
+    )
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.FISH_RC_FILE",
+        tmp_path / "fish" / "config.fish",
+    )
+
 
 def test_shell_command_enable_interactive(
     default_namespace, command_context, capsys, disable_stream_flush
@@ -172,6 +213,7 @@ def test_shell_command_plain_mode(command_context, capsys, disable_stream_flush)
         enable_capture=False,
         disable_interactive=False,
         plain=True,
+        quiet=False,
     )
     result = shell.shell_command.func(args, command_context)
 
@@ -290,6 +332,7 @@ def test_shell_command_single_operations(
         disable_interactive=disable_interactive,
         enable_capture=enable_capture,
         plain=False,
+        quiet=False,
     )
 
     result = shell.shell_command.func(args, command_context)
@@ -316,3 +359,616 @@ def test_shell_command_integration_content(
     # The content should be the BASH_INTERACTIVE constant from integrations
     # We don't need to test the exact content, just that something was written
     assert len(content) > 0
+
+
+def test_shell_command_integration_binary_path(
+    default_namespace, command_context, tmp_path
+):
+    default_namespace.enable_interactive = True
+    with mock.patch.object(shell, "get_c_binary", return_value="/opt/my tools/c"):
+        assert shell.shell_command.func(default_namespace, command_context) == 0
+
+    content = (tmp_path / ".bashrc.d" / "cla-interactive.bashrc").read_text()
+    assert "local c_binary='/opt/my tools/c'" in content
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    (
+        ("/usr/bin/c", "/usr/bin/c"),
+        ("/opt/my tools/c", "'/opt/my tools/c'"),
+        ("/opt/it's/c", "'/opt/it'\"'\"'s/c'"),
+        ("/opt/$HOME/c", "'/opt/$HOME/c'"),
+        ("/opt/ferramentas/cão", "$'/opt/ferramentas/c\\xc3\\xa3o'"),
+        ("/opt/a b/ç'", "$'/opt/a b/\\xc3\\xa7\\x27'"),
+    ),
+)
+def test_quote_shell_value(value, expected):
+    assert quote_shell_value(value) == expected
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    (
+        ("/usr/bin/c", "/usr/bin/c"),
+        ("/opt/my tools/c", "'/opt/my tools/c'"),
+        ("/opt/it's/c", "'/opt/it\\'s/c'"),
+        ("/opt/back\\slash/c", "'/opt/back\\\\slash/c'"),
+        ("/opt/$HOME/c", "'/opt/$HOME/c'"),
+        ("/opt/ferramentas/cão", "'/opt/ferramentas/cão'"),
+    ),
+)
+def test_quote_fish_value(value, expected):
+    assert quote_fish_value(value) == expected
+
+
+@pytest.mark.parametrize(
+    ("c_binary", "expected"),
+    (
+        ("/usr/bin/c", "local c_binary=/usr/bin/c\n"),
+        ("/home/user/my bin/c", "local c_binary='/home/user/my bin/c'\n"),
+        ("/home/usér/c", "local c_binary=$'/home/us\\xc3\\xa9r/c'\n"),
+    ),
+)
+def test_generate_bash_interactive(c_binary, expected):
+    content = generate_bash_interactive(c_binary)
+
+    assert expected in content
+    assert content.isascii()
+
+
+#: Command to check the syntax of a snippet, without running it, per shell.
+SYNTAX_CHECK_COMMANDS = {
+    shell.ShellType.BASH: ["bash", "-n"],
+    shell.ShellType.ZSH: ["zsh", "-n"],
+    shell.ShellType.FISH: ["fish", "--no-execute"],
+}
+
+
+@pytest.mark.parametrize(
+    ("shell_type", "c_binary", "expected"),
+    (
+        (shell.ShellType.BASH, "/opt/my tools/c", "'/opt/my tools/c'"),
+        (shell.ShellType.BASH, "/opt/usér/c", "$'/opt/us\\xc3\\xa9r/c'"),
+        (shell.ShellType.ZSH, "/opt/my tools/c", "'/opt/my tools/c'"),
+        (shell.ShellType.ZSH, "/opt/usér/c", "$'/opt/us\\xc3\\xa9r/c'"),
+        (shell.ShellType.FISH, "/opt/my tools/c", "'/opt/my tools/c'"),
+        (shell.ShellType.FISH, "/opt/usér/c", "'/opt/usér/c'"),
+    ),
+)
+def test_generate_integrations_quoting(shell_type, c_binary, expected):
+    snippets = [
+        generate_alias(
+            shell_type == shell.ShellType.FISH,
+            c_binary,
+            zsh=shell_type == shell.ShellType.ZSH,
+        )
+    ]
+    if shell_type == shell.ShellType.BASH:
+        snippets.append(generate_bash_interactive(c_binary))
+
+    assert f"    {expected} chat " in snippets[0]
+    if shell_type == shell.ShellType.BASH:
+        assert f"local c_binary={expected}\n" in snippets[1]
+
+    command = SYNTAX_CHECK_COMMANDS[shell_type]
+    if not shutil.which(command[0]):
+        pytest.skip(f"{shell_type.value} is not installed")
+
+    for snippet in snippets:
+        result = subprocess.run(command, input=snippet, text=True, capture_output=True)
+        assert result.returncode == 0, result.stderr
+
+
+@pytest.mark.parametrize(
+    ("shell_type", "location", "expected"),
+    [
+        (
+            shell.ShellType.BASH,
+            "bash-completion/completions/c",
+            "complete -o default -F _c_completion c",
+        ),
+        (shell.ShellType.ZSH, "zsh/site-functions/_c", "#compdef c"),
+        (shell.ShellType.FISH, "fish/completions/c.fish", "complete -c c -f"),
+    ],
+)
+def test_shell_command_enable_completion(
+    shell_type,
+    location,
+    expected,
+    default_namespace,
+    command_context,
+    capsys,
+    tmp_path,
+    disable_stream_flush,
+):
+    """Test installing the completion for each supported shell."""
+    default_namespace.enable_completion = True
+    default_namespace.shell_type = shell_type
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    completion_file = tmp_path / location
+    assert result == 0
+    assert f"Completion successfully installed at {completion_file}" in captured.out
+
+    content = completion_file.read_text()
+    assert expected in content
+    for subcommand in ("chat", "history", "shell"):
+        assert subcommand in content
+
+
+def test_shell_command_enable_completion_undetected_shell(
+    default_namespace, command_context, capsys, monkeypatch
+):
+    """Test installing the completion when the shell can't be detected."""
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell._detect_shell", lambda: None
+    )
+    default_namespace.enable_completion = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 81
+    assert "Couldn't detect your shell" in captured.err
+
+
+@pytest.mark.parametrize(
+    ("shell_type", "expected"),
+    [
+        ("bash", shell.ShellType.BASH),
+        ("zsh", shell.ShellType.ZSH),
+        ("fish", shell.ShellType.FISH),
+    ],
+)
+def test_shell_type_argument(shell_type, expected):
+    """Test that the --shell-type argument is converted to the enum."""
+    parser = shell._build_parser()
+    args = parser.parse_args(["shell", "--status", "--shell-type", shell_type])
+
+    assert args.shell_type == expected
+
+
+def test_shell_type_argument_invalid(capsys):
+    """Test that argparse rejects unsupported shells."""
+    parser = shell._build_parser()
+    with pytest.raises(SystemExit):
+        parser.parse_args(["shell", "--status", "--shell-type", "tcsh"])
+
+    captured = capsys.readouterr()
+    assert "invalid ShellType value: 'tcsh'" in captured.err
+
+
+def test_shell_command_enable_completion_not_writable(
+    default_namespace, command_context, capsys, monkeypatch
+):
+    """Test installing the completion in a non-writable location."""
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.write_file",
+        mock.Mock(side_effect=PermissionError(13, "Permission denied")),
+    )
+    default_namespace.enable_completion = True
+    default_namespace.shell_type = shell.ShellType.BASH
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 81
+    assert "Couldn't write the completion file" in captured.err
+    assert "Permission denied" in captured.err
+
+
+@pytest.mark.parametrize(
+    ("shell_type", "expected"),
+    [
+        (shell.ShellType.BASH, "complete -o default -F _c_completion c"),
+        (shell.ShellType.ZSH, "#compdef c"),
+        (shell.ShellType.FISH, "complete -c c -f"),
+    ],
+)
+def test_shell_command_print_completion(
+    shell_type, expected, default_namespace, command_context, capsys, tmp_path
+):
+    """Test printing the completion script to stdout."""
+    default_namespace.print_completion = True
+    default_namespace.shell_type = shell_type
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert expected in captured.out
+    assert "Completion successfully installed" not in captured.out
+    # Nothing should be installed in this mode.
+    assert not list(tmp_path.rglob("c*"))
+
+
+def test_shell_command_print_completion_undetected_shell(
+    default_namespace, command_context, capsys, monkeypatch
+):
+    """Test printing the completion script when the shell can't be detected."""
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell._detect_shell", lambda: None
+    )
+    default_namespace.print_completion = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 81
+    assert "Couldn't detect your shell" in captured.err
+    assert "Supported shells are: bash, zsh, fish." in captured.err
+    assert not captured.out
+
+
+def test_shell_command_enable_completion_detected_shell(
+    default_namespace, command_context, monkeypatch, tmp_path
+):
+    """Test installing the completion for the detected shell."""
+    monkeypatch.setenv("SHELL", "/usr/bin/fish")
+    default_namespace.enable_completion = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    assert result == 0
+    assert (tmp_path / "fish" / "completions" / "c.fish").exists()
+
+
+@pytest.mark.parametrize(
+    ("shell_path", "expected"),
+    [
+        ("/bin/bash", shell.ShellType.BASH),
+        ("/usr/bin/zsh", shell.ShellType.ZSH),
+        ("/usr/bin/fish", shell.ShellType.FISH),
+        ("/usr/bin/tcsh", None),
+    ],
+)
+def test_detect_shell(shell_path, expected, monkeypatch):
+    monkeypatch.setenv("SHELL", shell_path)
+    monkeypatch.setattr(
+        "command_line_assistant.commands.shell.os.getppid", lambda: 999999999
+    )
+
+    assert shell._detect_shell() == expected
+
+
+def test_detect_shell_from_parent_process(monkeypatch):
+    monkeypatch.delenv("SHELL", raising=False)
+    with mock.patch(
+        "command_line_assistant.commands.shell.Path.read_text", return_value="-zsh\n"
+    ):
+        assert shell._detect_shell() == shell.ShellType.ZSH
+
+
+def test_shell_command_status_all_shells(
+    default_namespace, command_context, capsys, tmp_path, disable_stream_flush
+):
+    """Test the status of the completion for all shells."""
+    zsh_completion = tmp_path / "zsh" / "site-functions" / "_c"
+    zsh_completion.parent.mkdir(parents=True)
+    zsh_completion.write_text("#compdef c")
+
+    default_namespace.status = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert f"zsh: completion installed at {zsh_completion}" in captured.out
+    assert "bash: completion not installed" in captured.out
+    assert "fish: completion not installed" in captured.out
+
+
+def test_shell_command_status_single_shell(
+    default_namespace, command_context, capsys, tmp_path, disable_stream_flush
+):
+    """Test the status of the completion for a specific shell."""
+    default_namespace.status = True
+    default_namespace.shell_type = shell.ShellType.BASH
+
+    result = shell.shell_command.func(default_namespace, command_context)
+    captured = capsys.readouterr()
+    assert result == 2
+    assert "bash: completion not installed" in captured.out
+    assert "zsh" not in captured.out
+
+    bash_completion = tmp_path / "bash-completion" / "completions" / "c"
+    bash_completion.parent.mkdir(parents=True)
+    bash_completion.write_text("complete -F _c_completion c")
+
+    result = shell.shell_command.func(default_namespace, command_context)
+    captured = capsys.readouterr()
+    assert result == 0
+    assert f"bash: completion installed at {bash_completion}" in captured.out
+
+    bash_completion.unlink()
+    result = shell.shell_command.func(default_namespace, command_context)
+    assert result == 2
+
+
+@pytest.mark.parametrize("shell_type", list(shell.ShellType))
+def test_shell_command_disable_completion(
+    shell_type, default_namespace, command_context, capsys, disable_stream_flush
+):
+    """Test installing and then removing the completion."""
+    default_namespace.shell_type = shell_type
+    default_namespace.enable_completion = True
+    assert shell.shell_command.func(default_namespace, command_context) == 0
+
+    completion_file, _ = shell._get_completion_target(shell_type)
+    assert completion_file.exists()
+
+    default_namespace.enable_completion = False
+    default_namespace.disable_completion = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert f"Removed {shell_type.value} completion from {completion_file}" in captured.out
+    assert not completion_file.exists()
+
+
+def test_shell_command_disable_completion_all_shells(
+    default_namespace, command_context, capsys, tmp_path, disable_stream_flush
+):
+    """Test removing the completion for all shells."""
+    for shell_type in (shell.ShellType.BASH, shell.ShellType.FISH):
+        default_namespace.shell_type = shell_type
+        default_namespace.enable_completion = True
+        shell.shell_command.func(default_namespace, command_context)
+
+    default_namespace.shell_type = None
+    default_namespace.enable_completion = False
+    default_namespace.disable_completion = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert "Removed bash completion" in captured.out
+    assert "Removed fish completion" in captured.out
+    assert "Removed zsh completion" not in captured.out
+    assert not (tmp_path / "bash-completion" / "completions" / "c").exists()
+    assert not (tmp_path / "fish" / "completions" / "c.fish").exists()
+
+
+def test_shell_command_disable_completion_not_installed(
+    default_namespace, command_context, capsys, tmp_path, disable_stream_flush
+):
+    """Test removing the completion when nothing is installed."""
+    # A directory in the completion location must never be removed.
+    (tmp_path / "zsh" / "site-functions" / "_c").mkdir(parents=True)
+
+    default_namespace.disable_completion = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert "It seems that the completion is not installed" in captured.out
+    assert (tmp_path / "zsh" / "site-functions" / "_c").is_dir()
+
+
+def test_shell_command_print_all_completions(
+    default_namespace, command_context, capsys, tmp_path
+):
+    """Test printing the completion scripts for all shells."""
+    default_namespace.print_all_completions = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    bash_header = f"# ===== bash: {tmp_path / 'bash-completion/completions/c'} ====="
+    zsh_header = f"# ===== zsh: {tmp_path / 'zsh/site-functions/_c'} ====="
+    fish_header = f"# ===== fish: {tmp_path / 'fish/completions/c.fish'} ====="
+    for header in (bash_header, zsh_header, fish_header):
+        assert header in captured.out
+
+    bash_index = captured.out.index(bash_header)
+    zsh_index = captured.out.index(zsh_header)
+    fish_index = captured.out.index(fish_header)
+    assert bash_index < zsh_index < fish_index
+    assert "complete -o default -F _c_completion c" in captured.out[:zsh_index]
+    assert "#compdef c" in captured.out[zsh_index:fish_index]
+    assert "complete -c c -f" in captured.out[fish_index:]
+
+
+@pytest.mark.parametrize(
+    ("shell_type", "filename"),
+    (
+        (shell.ShellType.BASH, "c"),
+        (shell.ShellType.ZSH, "_c"),
+        (shell.ShellType.FISH, "c.fish"),
+    ),
+)
+def test_release_completion_files_up_to_date(shell_type, filename):
+    """The packaged completions must be regenerated with `make completions`."""
+    _, generator = shell._get_completion_target(shell_type)
+    release_file = (
+        Path(__file__).parents[2] / "data" / "release" / "completions" / filename
+    )
+
+    expected = generator(shell._build_parser())
+    assert expected
+    assert release_file.read_text() == expected
+
+
+@pytest.mark.parametrize(
+    ("shell_type", "expected"),
+    (
+        (shell.ShellType.BASH, "alias '??'='__c_ask'"),
+        (shell.ShellType.ZSH, "alias '??'='noglob __c_ask'"),
+        (shell.ShellType.FISH, "abbr --add -- '??' __c_ask"),
+    ),
+)
+def test_shell_command_install_alias(
+    shell_type,
+    expected,
+    default_namespace,
+    command_context,
+    capsys,
+    disable_stream_flush,
+):
+    """Test installing the alias and installing it again."""
+    rc_file = shell._get_rc_file(shell_type)
+    rc_file.parent.mkdir(parents=True, exist_ok=True)
+    rc_file.write_text("export EDITOR=vim\n")
+
+    default_namespace.shell_type = shell_type
+    default_namespace.install_alias = True
+    assert shell.shell_command.func(default_namespace, command_context) == 0
+    contents = rc_file.read_text()
+
+    assert contents.startswith("export EDITOR=vim\n\n# >>> command-line-assistant")
+    assert expected in contents
+    assert contents.endswith("# <<< command-line-assistant alias <<<\n")
+
+    # Installing it again doesn't duplicate the block.
+    assert shell.shell_command.func(default_namespace, command_context) == 0
+    captured = capsys.readouterr()
+    assert rc_file.read_text() == contents
+    assert f"The alias is already installed in {rc_file}." in captured.out
+
+
+def test_shell_command_install_alias_updates_block(
+    default_namespace, command_context, tmp_path, disable_stream_flush
+):
+    """Test that an outdated alias block is replaced."""
+    rc_file = tmp_path / ".bashrc"
+    rc_file.write_text(
+        "# >>> command-line-assistant alias >>>\n"
+        "alias '??'='old'\n"
+        "# <<< command-line-assistant alias <<<\n"
+        "export EDITOR=vim\n"
+    )
+
+    default_namespace.shell_type = shell.ShellType.BASH
+    default_namespace.install_alias = True
+    assert shell.shell_command.func(default_namespace, command_context) == 0
+
+    contents = rc_file.read_text()
+    assert "'old'" not in contents
+    assert contents.count("# >>> command-line-assistant alias >>>") == 1
+    assert contents.endswith("<<<\nexport EDITOR=vim\n")
+
+
+@pytest.mark.parametrize(
+    ("contents", "expected"),
+    (
+        (None, "Couldn't find the rc file at"),
+        ("# <<< command-line-assistant alias <<<\n", "is malformed"),
+        ("# >>> command-line-assistant alias >>>\n", "is malformed"),
+    ),
+)
+def test_shell_command_install_alias_invalid_rc_file(
+    contents,
+    expected,
+    default_namespace,
+    command_context,
+    capsys,
+    tmp_path,
+    disable_stream_flush,
+):
+    """Test that the alias is never written to a missing or malformed rc file."""
+    rc_file = tmp_path / ".bashrc"
+    if contents is not None:
+        rc_file.write_text(contents)
+
+    default_namespace.shell_type = shell.ShellType.BASH
+    default_namespace.install_alias = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == ShellCommandException.code
+    assert expected in captured.out
+    if contents is not None:
+        assert rc_file.read_text() == contents
+
+
+def test_shell_command_install_alias_keeps_rc_file(
+    default_namespace, command_context, tmp_path, disable_stream_flush
+):
+    """Test that the rc file mode and symlinks are kept on installation."""
+    dotfile = tmp_path / "dotfiles" / "bashrc"
+    dotfile.parent.mkdir()
+    dotfile.write_text("export EDITOR=vim\n")
+    dotfile.chmod(0o640)
+    rc_file = tmp_path / ".bashrc"
+    rc_file.symlink_to(dotfile)
+
+    default_namespace.shell_type = shell.ShellType.BASH
+    default_namespace.install_alias = True
+    assert shell.shell_command.func(default_namespace, command_context) == 0
+
+    assert rc_file.is_symlink()
+    assert "# >>> command-line-assistant alias >>>" in dotfile.read_text()
+    assert dotfile.stat().st_mode & 0o777 == 0o640
+    assert sorted(path.name for path in dotfile.parent.iterdir()) == ["bashrc"]
+
+
+def test_shell_command_install_alias_write_error(
+    default_namespace,
+    command_context,
+    capsys,
+    tmp_path,
+    monkeypatch,
+    disable_stream_flush,
+):
+    """Test that a failed write leaves the rc file untouched."""
+    rc_file = tmp_path / ".bashrc"
+    rc_file.write_text("export EDITOR=vim\n")
+    monkeypatch.setattr(
+        shell.os,
+        "replace",
+        mock.Mock(side_effect=OSError(28, "No space left on device")),
+    )
+
+    default_namespace.shell_type = shell.ShellType.BASH
+    default_namespace.install_alias = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == ShellCommandException.code
+    assert f"Couldn't write the rc file at {rc_file}: No space left" in captured.out
+    assert rc_file.read_text() == "export EDITOR=vim\n"
+    assert [path.name for path in tmp_path.iterdir()] == [".bashrc"]
+
+
+def test_shell_command_uninstall_alias(
+    default_namespace, command_context, capsys, tmp_path, disable_stream_flush
+):
+    """Test that removing the alias restores the rc file."""
+    rc_file = tmp_path / ".zshrc"
+    rc_file.write_text("export EDITOR=vim\n")
+
+    default_namespace.shell_type = shell.ShellType.ZSH
+    default_namespace.install_alias = True
+    shell.shell_command.func(default_namespace, command_context)
+
+    default_namespace.install_alias = False
+    default_namespace.uninstall_alias = True
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert f"Removed the alias from {rc_file}." in captured.out
+    assert rc_file.read_text() == "export EDITOR=vim\n"
+
+    result = shell.shell_command.func(default_namespace, command_context)
+
+    captured = capsys.readouterr()
+    assert result == 0
+    assert "It seems that the alias is not installed" in captured.out
+
+
+@pytest.mark.parametrize(
+    ("fish", "zsh", "expected"),
+    (
+        (
+            False,
+            False,
+            '__c_ask() {\n    /usr/bin/c chat "$*"\n}\nalias \'??\'=\'__c_ask\'\n',
+        ),
+        (
+            False,
+            True,
+            '__c_ask() {\n    /usr/bin/c chat "$*"\n}\nalias \'??\'=\'noglob __c_ask\'\n',
+        ),
+        (True, False, 'function __c_ask\n    /usr/bin/c chat "$argv"\nend\n'),
+    ),
+)
+def test_generate_alias(fish, zsh, expected):
+    assert generate_alias(fish=fish, zsh=zsh).startswith(expected)
@@ -1,4 +1,6 @@
-from command_line_assistant.config.schemas.backend import AuthSchema
+import pytest
+
+from command_line_assistant.config.schemas.backend import AuthSchema, BackendSchema
 
 
 # TODO(r0x0d): Once we remove the depreaction notice, remove this as well.
@@ -12,3 +14,124 @@ def test_verify_ssl_deprecation_notice(caplog):
         "Ignoring Verify SSL option as it has no effect anymore."
         in caplog.records[-1].message
     )
+
+
+@pytest.mark.parametrize(
+    ("endpoint", "expected"),
+    (
+        ("https://localhost", "https://localhost"),
+        ("https://localhost/", "https://localhost"),
+        ("http://localhost:8080/api/v1//", "http://localhost:8080/api/v1"),
+        ("  https://example.com/api/  ", "https://example.com/api"),
+    ),
+)
+def test_backend_endpoint_normalization(endpoint, expected):
+    assert BackendSchema(endpoint=endpoint).endpoint == expected
+
+
+@pytest.mark.parametrize(
+    ("endpoint",),
+    (
+        ("localhost:8080",),
+        ("example.com/api",),
+        ("ftp://example.com",),
+        ("https://",),
+        ("",),
+    ),
+)
+def test_backend_endpoint_invalid(endpoint):
+    with pytest.raises(ValueError, match="is not a valid URL"):
+        BackendSchema(endpoint=endpoint)
+
+
+def test_backend_request_template():
+    template = '{"query": "${question}", "os": "${system_info.os}"}'
+    assert BackendSchema(request_template=template).request_template == template
+
+
+@pytest.mark.parametrize(
+    ("template",),
+    (
+        ("not json",),
+        ('{"query": "${question.text}"}',),
+    ),
+)
+def test_backend_request_template_invalid(template):
+    with pytest.raises(ValueError, match="Invalid backend request_template"):
+        BackendSchema(request_template=template)
+
+
+@pytest.mark.parametrize(
+    ("response_path",),
+    (
+        ("",),
+        ("data.",),
+        ("data..text",),
+    ),
+)
+def test_backend_response_path_invalid(response_path):
+    with pytest.raises(ValueError, match="response_path"):
+        BackendSchema(response_path=response_path)
+
+
+@pytest.mark.parametrize(("max_files",), ((0,), (-1,)))
+def test_backend_debug_dump_max_files_invalid(max_files):
+    with pytest.raises(ValueError, match="debug_dump_max_files must be at least 1"):
+        BackendSchema(debug_dump_max_files=max_files)
+
+
+def test_backend_empty_response_message_invalid():
+    with pytest.raises(ValueError, match="empty_response_message can't be empty"):
+        BackendSchema(empty_response_message=" ")
+
+
+@pytest.mark.parametrize(
+    ("proxies",),
+    (
+        ({"http": "http://example-host:8002"},),
+        ({"https": "https://example-host:8002"},),
+        ({"http": "username:password@localhost:8002"},),
+        ({"https": "socks5h://localhost:1080"},),
+    ),
+)
+def test_backend_proxies(proxies):
+    assert BackendSchema(proxies=proxies).proxies == proxies
+
+
+@pytest.mark.parametrize(
+    ("proxies",),
+    (
+        ({"http": "http://"},),
+        ({"http": "ftp://example-host:8002"},),
+        ({"https": "https://example-host:port"},),
+        ({"https": ""},),
+    ),
+)
+def test_backend_proxies_invalid(proxies):
+    with pytest.raises(ValueError, match="proxy .* set in the config.toml is not"):
+        BackendSchema(proxies=proxies)
+
+
+def test_backend_proxies_invalid_from_environment(monkeypatch):
+    monkeypatch.setenv("https_proxy", "https://example-host:port")
+
+    with pytest.raises(
+        ValueError,
+        match="The https proxy .* set in the https_proxy environment variable is not",
+    ):
+        BackendSchema()
+
+
+@pytest.mark.parametrize(
+    ("no_proxy", "environment", "expected"),
+    (
+        ([], "", []),
+        (["internal.example.com", " .corp "], "", ["internal.example.com", ".corp"]),
+        ([], "localhost, .corp,", ["localhost", ".corp"]),
+        (["internal.example.com"], "localhost", ["internal.example.com"]),
+    ),
+)
+def test_backend_no_proxy(no_proxy, environment, expected, monkeypatch):
+    monkeypatch.setenv("no_proxy", environment)
+
+    assert BackendSchema(no_proxy=no_proxy).no_proxy == expected
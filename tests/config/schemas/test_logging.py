@@ -10,3 +10,15 @@ def test_logging_schema_invalid_level():
         ValueError, match="The requested level 'NOT_FOUND' is not allowed."
     ):
         LoggingSchema(level=level)
+
+
+@pytest.mark.parametrize(
+    ("log_format", "expected"), (("JSON", "json"), ("text", "text"))
+)
+def test_logging_schema_format(log_format, expected):
+    assert LoggingSchema(format=log_format).format == expected
+
+
+def test_logging_schema_invalid_format():
+    with pytest.raises(ValueError, match="The requested format 'xml' is not allowed."):
+        LoggingSchema(format="xml")
@@ -6,6 +6,7 @@ from command_line_assistant.config.schemas.backend import AuthSchema, BackendSch
 from command_line_assistant.config.schemas.database import DatabaseSchema
 from command_line_assistant.config.schemas.history import HistorySchema
 from command_line_assistant.config.schemas.logging import LoggingSchema
+from command_line_assistant.config.schemas.privacy import PrivacySchema
 
 
 @pytest.mark.parametrize(
@@ -16,6 +17,7 @@ from command_line_assistant.config.schemas.logging import LoggingSchema
         (HistorySchema,),
         (AuthSchema,),
         (DatabaseSchema,),
+        (PrivacySchema,),
     ),
 )
 def test_initialize_schemas_default_values(schema):
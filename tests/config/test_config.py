@@ -10,6 +10,12 @@ else:
     import tomli as tomllib
 
 
+@pytest.fixture(autouse=True)
+def clean_config_environment(monkeypatch):
+    monkeypatch.delenv(config.CONFIG_FILE_ENV, raising=False)
+    monkeypatch.delenv("XDG_CONFIG_HOME", raising=False)
+
+
 @pytest.fixture
 def get_config_template(tmp_path) -> str:
     return f"""\
@@ -40,6 +46,21 @@ def test_load_config_file(tmp_path, monkeypatch, get_config_template):
     assert isinstance(instance, config.Config)
 
     assert instance.history.enabled
+    assert instance.privacy.send_system_info
+
+
+def test_load_config_file_privacy(tmp_path, monkeypatch, get_config_template):
+    config_file_path = tmp_path
+    config_file = config_file_path / "command-line-assistant" / "config.toml"
+    config_file.parent.mkdir()
+    config_file.write_text(
+        get_config_template + "\n[privacy]\nsend_system_info = false\n"
+    )
+
+    monkeypatch.setattr(config, "get_xdg_config_path", lambda: config_file_path)
+    instance = config.load_config_file()
+
+    assert not instance.privacy.send_system_info
 
 
 def test_load_config_file_not_found(tmp_path, monkeypatch):
@@ -63,3 +84,108 @@ enforce_script = False
 
     with pytest.raises(tomllib.TOMLDecodeError):
         config.load_config_file()
+
+
+def test_load_config_file_invalid_value(
+    tmp_path, monkeypatch, get_config_template, caplog
+):
+    config_file_path = tmp_path
+    config_file = config_file_path / "command-line-assistant" / "config.toml"
+    config_file.parent.mkdir()
+    config_file.write_text(
+        get_config_template.replace("https://localhost", "localhost:8080")
+    )
+
+    monkeypatch.setattr(config, "get_xdg_config_path", lambda: config_file_path)
+
+    with pytest.raises(ValueError, match="is not a valid URL"):
+        config.load_config_file()
+
+    assert "The backend endpoint 'localhost:8080' is not a valid URL" in caplog.text
+
+
+def test_load_config_file_invalid_environment_proxy(
+    tmp_path, monkeypatch, get_config_template, caplog
+):
+    config_file_path = tmp_path
+    config_file = config_file_path / "command-line-assistant" / "config.toml"
+    config_file.parent.mkdir()
+    config_file.write_text(get_config_template)
+
+    monkeypatch.setattr(config, "get_xdg_config_path", lambda: config_file_path)
+    monkeypatch.delenv("https_proxy", raising=False)
+    monkeypatch.setenv("http_proxy", "ftp://example-host:8002")
+
+    with pytest.raises(ValueError):
+        config.load_config_file()
+
+    assert "set in the http_proxy environment variable" in caplog.text
+
+
+@pytest.mark.parametrize(
+    ("argument", "environment", "config_home", "expected"),
+    (
+        ("argument", "environment", "config_home", "argument"),
+        (None, "environment", "config_home", "environment"),
+        (None, None, "config_home", "config_home"),
+        (None, None, None, "config_dirs"),
+    ),
+)
+def test_get_config_file_path(
+    argument, environment, config_home, expected, tmp_path, monkeypatch
+):
+    paths = {
+        "argument": tmp_path / "argument.toml",
+        "environment": tmp_path / "environment.toml",
+        "config_home": tmp_path / "home" / "command-line-assistant" / "config.toml",
+        "config_dirs": tmp_path / "xdg" / "command-line-assistant" / "config.toml",
+    }
+    for path in paths.values():
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.touch()
+
+    monkeypatch.setattr(config, "get_xdg_config_path", lambda: tmp_path / "xdg")
+    if environment:
+        monkeypatch.setenv(config.CONFIG_FILE_ENV, str(paths[environment]))
+    if config_home:
+        monkeypatch.setenv("XDG_CONFIG_HOME", str(tmp_path / "home"))
+
+    result = config.get_config_file_path(paths[argument] if argument else None)
+
+    assert result == paths[expected]
+
+
+def test_get_config_file_path_missing_config_home(tmp_path, monkeypatch):
+    """A missing file in XDG_CONFIG_HOME falls back to XDG_CONFIG_DIRS."""
+    monkeypatch.setenv("XDG_CONFIG_HOME", str(tmp_path / "home"))
+    monkeypatch.setattr(config, "get_xdg_config_path", lambda: tmp_path / "xdg")
+
+    assert config.get_config_file_path() == (
+        tmp_path / "xdg" / "command-line-assistant" / "config.toml"
+    )
+
+
+@pytest.mark.parametrize(
+    ("argument", "environment", "source"),
+    (
+        ("missing.toml", None, "--config"),
+        (None, "missing.toml", "CLAD_CONFIG"),
+    ),
+)
+def test_get_config_file_path_explicit_missing(
+    argument, environment, source, tmp_path, monkeypatch
+):
+    if environment:
+        monkeypatch.setenv(config.CONFIG_FILE_ENV, str(tmp_path / environment))
+
+    with pytest.raises(FileNotFoundError, match=f"given through {source} doesn't"):
+        config.get_config_file_path(tmp_path / argument if argument else None)
+
+
+def test_load_config_file_explicit_path(tmp_path, get_config_template):
+    config_file = tmp_path / "custom.toml"
+    config_file.write_text(get_config_template)
+
+    instance = config.load_config_file(config_file)
+
+    assert instance.backend.endpoint == "https://localhost"
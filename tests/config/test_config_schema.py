@@ -0,0 +1,36 @@
+import dataclasses
+import json
+import typing
+from pathlib import Path
+
+import pytest
+
+from command_line_assistant import config
+
+SCHEMA_FILE = Path(__file__).parents[2] / "schemas" / "config.schema.json"
+
+
+@pytest.fixture
+def config_schema():
+    return json.loads(SCHEMA_FILE.read_text())
+
+
+def _assert_fields(schema: type, properties: dict) -> None:
+    hints = typing.get_type_hints(schema)
+    for field in dataclasses.fields(schema):
+        assert field.name in properties, f"{schema.__name__}.{field.name}"
+        if dataclasses.is_dataclass(hints[field.name]):
+            _assert_fields(hints[field.name], properties[field.name]["properties"])
+
+
+def test_config_schema_is_valid_json(config_schema):
+    assert config_schema["type"] == "object"
+    assert "backend" in config_schema["properties"]
+    assert config_schema["properties"]["backend"]["properties"]["auth"]["properties"][
+        "verify_ssl"
+    ]["deprecated"]
+
+
+def test_config_schema_up_to_date(config_schema):
+    """The schema must be regenerated with `make config-schema`."""
+    _assert_fields(config.Config, config_schema["properties"])
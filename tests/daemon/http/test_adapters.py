@@ -0,0 +1,51 @@
+import socket
+from unittest.mock import patch
+
+import pytest
+from urllib3.connection import HTTPConnection
+
+from command_line_assistant.daemon.http import adapters
+
+
+@pytest.mark.parametrize(("tcp_keepalive_secs",), ((None,), (0,)))
+def test_get_keepalive_socket_options_disabled(tcp_keepalive_secs):
+    options = adapters.get_keepalive_socket_options(tcp_keepalive_secs)
+
+    assert options == HTTPConnection.default_socket_options
+
+
+def test_get_keepalive_socket_options():
+    options = adapters.get_keepalive_socket_options(60)
+
+    assert (socket.SOL_SOCKET, socket.SO_KEEPALIVE, 1) in options
+    if hasattr(socket, "TCP_KEEPIDLE"):
+        assert (socket.IPPROTO_TCP, socket.TCP_KEEPIDLE, 60) in options
+
+
+def test_retry_adapter_socket_options():
+    adapter = adapters.RetryAdapter(tcp_keepalive_secs=30)
+
+    assert adapter.poolmanager.connection_pool_kw[
+        "socket_options"
+    ] == adapters.get_keepalive_socket_options(30)
+
+
+def test_retry_adapter_proxy_socket_options():
+    adapter = adapters.RetryAdapter(tcp_keepalive_secs=30)
+
+    manager = adapter.proxy_manager_for("http://proxy.example.com:3128")
+
+    assert manager.connection_pool_kw[
+        "socket_options"
+    ] == adapters.get_keepalive_socket_options(30)
+
+
+def test_retry_adapter_socks_proxy_socket_options():
+    adapter = adapters.RetryAdapter(tcp_keepalive_secs=30)
+
+    with patch("requests.adapters.SOCKSProxyManager") as socks_proxy_manager:
+        adapter.proxy_manager_for("socks5h://proxy.example.com:1080")
+
+    assert socks_proxy_manager.call_args.kwargs[
+        "socket_options"
+    ] == adapters.get_keepalive_socket_options(30)
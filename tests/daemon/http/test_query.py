@@ -1,11 +1,19 @@
+import json
 from http import HTTPStatus
 from unittest.mock import patch
 
 import pytest
 import responses
+from requests.exceptions import ConnectionError, ConnectTimeout, ReadTimeout, SSLError
+from urllib3.exceptions import MaxRetryError, ReadTimeoutError
 
 from command_line_assistant.daemon.http import query
-from command_line_assistant.dbus.exceptions import RequestFailedError
+from command_line_assistant.dbus.exceptions import (
+    BackendTimeoutError,
+    BackendUnreachableError,
+    RateLimitExceededError,
+    RequestFailedError,
+)
 
 
 @pytest.fixture
@@ -32,6 +40,81 @@ def test_handle_query(default_payload, mock_config):
     assert result == "test"
 
 
+@responses.activate
+@pytest.mark.parametrize(
+    ("response_path", "body"),
+    (
+        ("data.text", {"data": {"text": "test"}}),
+        ("result.answer", {"result": {"answer": "test"}}),
+        ("response", {"response": "test"}),
+        (
+            "choices.0.message.content",
+            {"choices": [{"message": {"content": "test"}}]},
+        ),
+        ("choices.1", {"choices": ["first", "test"]}),
+    ),
+)
+def test_handle_query_response_path(default_payload, mock_config, response_path, body):
+    mock_config.backend.response_path = response_path
+    responses.post(url="http://localhost/infer", json=body)
+
+    assert query.submit(default_payload, config=mock_config) == "test"
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("response_path", "body"),
+    (
+        ("data.text", {"result": {"answer": "test"}}),
+        ("choices.1.content", {"choices": [{"content": "test"}]}),
+        ("choices.first", {"choices": [{"content": "test"}]}),
+        ("data", {"data": {"text": "test"}}),
+    ),
+)
+def test_handle_query_response_path_missing(
+    default_payload, mock_config, response_path, body
+):
+    mock_config.backend.response_path = response_path
+    responses.post(url="http://localhost/infer", json=body)
+
+    with pytest.raises(
+        RequestFailedError,
+        match=f"doesn't contain any text in '{response_path}'",
+    ):
+        query.submit(default_payload, config=mock_config)
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("response_path", "body"),
+    (
+        ("data.text", {"data": {}}),
+        ("data.text", {"data": {"text": None}}),
+        ("choices.1", {"choices": ["first"]}),
+    ),
+)
+def test_handle_query_response_path_without_text(
+    default_payload, mock_config, response_path, body
+):
+    mock_config.backend.response_path = response_path
+    responses.post(url="http://localhost/infer", json=body)
+
+    assert (
+        query.submit(default_payload, config=mock_config)
+        == mock_config.backend.empty_response_message
+    )
+
+
+@responses.activate
+def test_submit_retry_on_missing_text(mock_config, default_payload):
+    mock_config.backend.retry_on_empty = True
+    responses.post(url="http://localhost/infer", json={"data": {}})
+    responses.post(url="http://localhost/infer", json={"data": {"text": "test"}})
+
+    assert query.submit(default_payload, config=mock_config) == "test"
+    assert len(responses.calls) == 2
+
+
 @responses.activate
 def test_handle_query_raising_status_from_api(mock_config, default_payload):
     responses.post(
@@ -75,7 +158,7 @@ def test_submit_empty_query(mock_config):
     )
 
     result = query.submit(empty_payload, config=mock_config)
-    assert result == ""
+    assert result == mock_config.backend.empty_response_message
 
 
 error_case_names = "status_code,detail,expected_error_message"
@@ -218,6 +301,53 @@ def test_handle_error_response_invalid_json(
         query.submit(default_payload, config=mock_config)
 
 
+@responses.activate
+@pytest.mark.parametrize(
+    ("exception", "expected_error", "expected_message"),
+    (
+        (
+            ConnectTimeout("connect timed out"),
+            BackendTimeoutError,
+            "The server didn't answer within 30 seconds.",
+        ),
+        (
+            ReadTimeout("read timed out"),
+            BackendTimeoutError,
+            "The server didn't answer within 30 seconds.",
+        ),
+        (
+            ConnectionError(
+                MaxRetryError(
+                    None, "/infer", ReadTimeoutError(None, "/infer", "read timed out")
+                )
+            ),
+            BackendTimeoutError,
+            "The server didn't answer within 30 seconds.",
+        ),
+        (
+            ConnectionError("Connection refused"),
+            BackendUnreachableError,
+            "Couldn't connect to the server at http://localhost.",
+        ),
+        (
+            SSLError("certificate verify failed"),
+            RequestFailedError,
+            "Communication error with the server: certificate verify failed.",
+        ),
+    ),
+)
+def test_submit_connection_failures(
+    mock_config, default_payload, exception, expected_error, expected_message
+):
+    responses.post(url="http://localhost/infer", body=exception)
+
+    with pytest.raises(expected_error) as exc_info:
+        query.submit(default_payload, config=mock_config)
+
+    assert type(exc_info.value) is expected_error
+    assert expected_message in str(exc_info.value)
+
+
 def test_submit_with_rhsm_cert_oserror(mock_config, default_payload):
     """Test that OSError with RHSM certificate path raises specific error message"""
     # Mock the session to raise OSError with the specific path
@@ -244,3 +374,314 @@ def test_submit_with_generic_oserror(mock_config, default_payload):
 
         with pytest.raises(OSError, match="Generic OS error"):
             query.submit(default_payload, config=mock_config)
+
+
+def test_dump_filename():
+    first = query._dump_filename()
+    second = query._dump_filename()
+
+    assert first.startswith("request-")
+    assert first.endswith(".json")
+    assert first != second
+
+
+@responses.activate
+def test_submit_dumps_request(mock_config, default_payload, tmp_path):
+    dump_dir = tmp_path / "dumps"
+    mock_config.backend.debug_dump_dir = dump_dir
+    responses.post(url="http://localhost/infer", json={"data": {"text": "test"}})
+
+    query.submit(default_payload, config=mock_config)
+
+    dumps = list(dump_dir.glob("request-*.json"))
+    assert len(dumps) == 1
+    assert json.loads(dumps[0].read_text()) == default_payload
+
+
+@responses.activate
+def test_submit_skips_dump_without_dir(mock_config, default_payload):
+    responses.post(url="http://localhost/infer", json={"data": {"text": "test"}})
+
+    with patch("command_line_assistant.daemon.http.query._dump_request") as mock_dump:
+        query.submit(default_payload, config=mock_config)
+
+    mock_dump.assert_not_called()
+
+
+def test_dump_request_rotates_files(tmp_path, default_payload):
+    for index in range(3):
+        dump = tmp_path / f"request-2025010{index}T000000000000-0000000{index}.json"
+        dump.write_text("{}")
+
+    query._dump_request(default_payload, tmp_path, max_files=2)
+
+    dumps = sorted(path.name for path in tmp_path.glob("request-*.json"))
+    assert len(dumps) == 2
+    assert "request-20250100T000000000000-00000000.json" not in dumps
+    assert "request-20250101T000000000000-00000001.json" not in dumps
+
+
+def test_dump_request_failure_is_logged(tmp_path, default_payload, caplog):
+    dump_dir = tmp_path / "dumps"
+    dump_dir.write_text("not a directory")
+
+    query._dump_request(default_payload, dump_dir, max_files=2)
+
+    assert "Failed to dump backend request" in caplog.text
+
+
+def test_dump_request_failure_removes_temporary_file(tmp_path, default_payload, caplog):
+    with patch(
+        "command_line_assistant.daemon.http.query.os.replace",
+        side_effect=OSError("No space left on device"),
+    ):
+        query._dump_request(default_payload, tmp_path, max_files=2)
+
+    assert "Failed to dump backend request" in caplog.text
+    assert not list(tmp_path.iterdir())
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("citations", "expected"),
+    [
+        (
+            [{"title": "RHEL docs", "url": "https://docs.redhat.com"}],
+            "test\n\nSources:\n- RHEL docs: https://docs.redhat.com",
+        ),
+        (
+            ["https://access.redhat.com", {"link": "https://docs.redhat.com"}],
+            "test\n\nSources:\n- https://access.redhat.com\n- https://docs.redhat.com",
+        ),
+        ([], "test"),
+        ("not a list", "test"),
+    ],
+)
+def test_submit_include_citations(mock_config, default_payload, citations, expected):
+    mock_config.backend.include_citations = True
+    responses.post(
+        url="http://localhost/infer",
+        json={"data": {"text": "test", "citations": citations}},
+    )
+
+    assert query.submit(default_payload, config=mock_config) == expected
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("response_path", "body"),
+    [
+        ("0.content", [{"content": "test"}]),
+        ("data.0.content", {"data": [{"content": "test"}]}),
+    ],
+)
+def test_submit_include_citations_without_data_object(
+    mock_config, default_payload, response_path, body
+):
+    mock_config.backend.include_citations = True
+    mock_config.backend.response_path = response_path
+    responses.post(url="http://localhost/infer", json=body)
+
+    assert query.submit(default_payload, config=mock_config) == "test"
+
+
+@responses.activate
+def test_submit_ignores_citations_by_default(mock_config, default_payload):
+    responses.post(
+        url="http://localhost/infer",
+        json={"data": {"text": "test", "citations": ["https://docs.redhat.com"]}},
+    )
+
+    assert query.submit(default_payload, config=mock_config) == "test"
+
+
+@pytest.mark.parametrize(
+    ("elapsed_ms", "threshold_ms", "expected"),
+    [
+        (1500, 1000, True),
+        (1000, 1000, False),
+        (500, 1000, False),
+        (1500, 0, False),
+    ],
+)
+def test_is_slow_request(elapsed_ms, threshold_ms, expected):
+    assert query._is_slow_request(elapsed_ms, threshold_ms) == expected
+
+
+@responses.activate
+@pytest.mark.parametrize(("threshold_ms", "logged"), [(1, True), (0, False)])
+def test_submit_logs_slow_request(
+    mock_config, default_payload, caplog, threshold_ms, logged
+):
+    mock_config.backend.slow_request_threshold_ms = threshold_ms
+    responses.post(url="http://localhost/infer", json={"data": {"text": "test"}})
+
+    with patch("command_line_assistant.daemon.http.query.time.monotonic_ns") as clock:
+        clock.side_effect = [0, 0, 5_000_000, 6_000_000]
+        query.submit(default_payload, config=mock_config)
+
+    assert ("exceeding the threshold of 1 ms" in caplog.text) == logged
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("body", "expected_error"),
+    [({"data": {"text": "test"}}, None), ({"result": {}}, RequestFailedError)],
+)
+def test_submit_logs_latency(
+    mock_config, default_payload, caplog, body, expected_error
+):
+    responses.post(url="http://localhost/infer", json=body)
+
+    with patch("command_line_assistant.daemon.http.query.time.monotonic_ns") as clock:
+        clock.side_effect = [0, 0, 5_000_000, 6_000_000]
+        if expected_error:
+            with pytest.raises(expected_error):
+                query.submit(default_payload, config=mock_config)
+        else:
+            query.submit(default_payload, config=mock_config)
+
+    assert "Handled the query to http://localhost/infer in 6 ms" in caplog.text
+
+
+@responses.activate
+def test_submit_retry_on_empty(mock_config, default_payload, caplog):
+    mock_config.backend.retry_on_empty = True
+    responses.post(url="http://localhost/infer", json={"data": {"text": ""}})
+    responses.post(url="http://localhost/infer", json={"data": {"text": "test"}})
+
+    assert query.submit(default_payload, config=mock_config) == "test"
+    assert len(responses.calls) == 2
+    assert "Received an empty response from LLM backend. Retrying (1/3)." in caplog.text
+
+
+@responses.activate
+def test_submit_retry_on_empty_exhausted(mock_config, default_payload):
+    mock_config.backend.retry_on_empty = True
+    responses.post(url="http://localhost/infer", json={"data": {"text": ""}})
+
+    with patch("command_line_assistant.daemon.http.query.time.sleep") as sleep:
+        result = query.submit(default_payload, config=mock_config)
+
+    assert result == mock_config.backend.empty_response_message
+    assert len(responses.calls) == 4
+    assert [call.args[0] for call in sleep.call_args_list] == [0.1, 0.2, 0.4]
+
+
+@responses.activate
+def test_submit_retry_on_empty_within_timeout(mock_config, default_payload, caplog):
+    mock_config.backend.retry_on_empty = True
+    mock_config.backend.timeout = 0.25
+    responses.post(url="http://localhost/infer", json={"data": {"text": ""}})
+
+    with patch("command_line_assistant.daemon.http.query.time.sleep") as sleep:
+        result = query.submit(default_payload, config=mock_config)
+
+    assert result == mock_config.backend.empty_response_message
+    # The third retry would wait 0.4 seconds, which is more than the timeout.
+    assert len(responses.calls) == 3
+    assert [call.args[0] for call in sleep.call_args_list] == [0.1, 0.2]
+    assert all(
+        call.request.req_kwargs["timeout"] < 0.25 for call in responses.calls[1:]
+    )
+    assert "as the timeout of 0.25 seconds was reached" in caplog.text
+
+
+@responses.activate
+def test_submit_no_retry_on_empty_by_default(mock_config, default_payload):
+    responses.post(url="http://localhost/infer", json={"data": {"text": ""}})
+    responses.post(url="http://localhost/infer", json={"data": {"text": "test"}})
+
+    assert (
+        query.submit(default_payload, config=mock_config)
+        == mock_config.backend.empty_response_message
+    )
+    assert len(responses.calls) == 1
+
+
+@responses.activate
+@pytest.mark.parametrize(("text",), (("",), ("  \n ",)))
+def test_submit_empty_response_fallback(mock_config, default_payload, text):
+    mock_config.backend.empty_response_message = "Nothing to say."
+    responses.post(url="http://localhost/infer", json={"data": {"text": text}})
+
+    assert query.submit(default_payload, config=mock_config) == "Nothing to say."
+
+
+@responses.activate
+def test_submit_error_on_empty(mock_config, default_payload):
+    mock_config.backend.error_on_empty = True
+    responses.post(url="http://localhost/infer", json={"data": {"text": " "}})
+
+    with pytest.raises(
+        RequestFailedError, match="The server returned an empty response."
+    ):
+        query.submit(default_payload, config=mock_config)
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("collapse_repetition", "expected"),
+    (
+        (True, "Run dnf update."),
+        (False, "Run dnf update. Run dnf update. Run dnf update."),
+    ),
+)
+def test_submit_collapse_repetition(
+    mock_config, default_payload, collapse_repetition, expected
+):
+    mock_config.backend.collapse_repetition = collapse_repetition
+    responses.post(
+        url="http://localhost/infer",
+        json={"data": {"text": "Run dnf update. Run dnf update. Run dnf update."}},
+    )
+
+    assert query.submit(default_payload, config=mock_config) == expected
+
+
+@pytest.mark.parametrize(
+    ("text", "expected"),
+    (
+        ("Hello. Hello. Hello.", "Hello."),
+        ("Hello. World. Hello.", "Hello. World. Hello."),
+        ("Hi!\n\nHi!\n\nHi!\n\nBye.", "Hi!\n\nBye."),
+        ("First.\n\nSecond.\n\nFirst.", "First.\n\nSecond.\n\nFirst."),
+        (
+            "```\nls\n\nls\n```\n\n```\nls\n\nls\n```",
+            "```\nls\n\nls\n```\n\n```\nls\n\nls\n```",
+        ),
+        ("Use `ls`. Use `ls`.", "Use `ls`."),
+        ("", ""),
+    ),
+)
+def test_collapse_repetition(text, expected):
+    assert query._collapse_repetition(text) == expected
+
+
+@responses.activate
+@pytest.mark.parametrize(
+    ("headers", "expected_suffix"),
+    [
+        ({"Retry-After": "60"}, " Retry after 60 seconds."),
+        (
+            {"Retry-After": "Wed, 21 Oct 2026 07:28:00 GMT"},
+            " Retry after Wed, 21 Oct 2026 07:28:00 GMT.",
+        ),
+        ({}, ""),
+    ],
+)
+def test_handle_rate_limit(mock_config, default_payload, headers, expected_suffix):
+    responses.post(
+        url="http://localhost/infer",
+        status=HTTPStatus.TOO_MANY_REQUESTS,
+        json={"detail": "Rate limit reached."},
+        headers=headers,
+    )
+
+    with pytest.raises(RateLimitExceededError) as exc_info:
+        query.submit(default_payload, config=mock_config)
+
+    assert str(exc_info.value) == (
+        "Too many requests: Rate limit exceeded. Please try again later. "
+        f"Rate limit reached.{expected_suffix}"
+    )
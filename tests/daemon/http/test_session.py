@@ -3,7 +3,11 @@ from unittest.mock import MagicMock, patch
 import pytest
 
 from command_line_assistant.constants import VERSION
-from command_line_assistant.daemon.http.session import get_session
+from command_line_assistant.daemon.http.adapters import get_keepalive_socket_options
+from command_line_assistant.daemon.http.session import (
+    get_request_proxies,
+    get_session,
+)
 
 
 def test_session_headers(mock_config):
@@ -74,3 +78,70 @@ def test_session_with_proxies(proxies, mock_config):
     session = get_session(mock_config)
 
     assert session.proxies == proxies
+
+
+def test_session_with_tcp_keepalive(mock_config):
+    mock_config.backend.tcp_keepalive_secs = 45
+
+    session = get_session(mock_config)
+
+    adapter = session.adapters[mock_config.backend.endpoint]
+    assert adapter.poolmanager.connection_pool_kw[
+        "socket_options"
+    ] == get_keepalive_socket_options(45)
+
+
+@pytest.mark.parametrize(
+    ("no_proxy", "bypassed"),
+    (
+        (["internal.example.com"], True),
+        ([".example.com"], True),
+        (["other.example.com"], False),
+        ([], False),
+    ),
+)
+def test_session_with_no_proxy(no_proxy, bypassed, mock_config):
+    proxies = {"https": "https://proxy.example.com:8002"}
+    mock_config.backend.endpoint = "https://internal.example.com"
+    mock_config.backend.proxies = proxies
+    mock_config.backend.no_proxy = no_proxy
+
+    session = get_session(mock_config)
+
+    assert session.proxies == ({} if bypassed else proxies)
+    assert session.trust_env
+
+
+@pytest.mark.parametrize(
+    ("no_proxy", "expected_proxies"),
+    (
+        (["internal.example.com"], {}),
+        (["other.example.com"], {"https": "https://proxy.example.com:8002"}),
+    ),
+)
+def test_session_with_no_proxy_keeps_environment(
+    no_proxy, expected_proxies, mock_config, monkeypatch
+):
+    """Only the proxies from the environment are skipped for excluded hosts."""
+    monkeypatch.setenv("REQUESTS_CA_BUNDLE", "/etc/pki/custom-ca.pem")
+    monkeypatch.setenv("https_proxy", "https://proxy.example.com:8002")
+    monkeypatch.delenv("no_proxy", raising=False)
+    monkeypatch.delenv("NO_PROXY", raising=False)
+    mock_config.backend.endpoint = "https://internal.example.com"
+    mock_config.backend.no_proxy = no_proxy
+
+    session = get_session(mock_config)
+    settings = session.merge_environment_settings(
+        mock_config.backend.endpoint,
+        get_request_proxies(mock_config),
+        None,
+        None,
+        None,
+    )
+
+    assert settings["verify"] == "/etc/pki/custom-ca.pem"
+    assert {
+        scheme: proxy
+        for scheme, proxy in settings["proxies"].items()
+        if scheme != "no_proxy"
+    } == expected_proxies
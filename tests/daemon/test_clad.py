@@ -6,6 +6,12 @@ from command_line_assistant.config import Config
 from command_line_assistant.daemon.clad import daemonize
 
 
+@pytest.fixture(autouse=True)
+def mock_argv():
+    with patch("sys.argv", ["clad"]):
+        yield
+
+
 @pytest.fixture
 def mock_config():
     return Mock(spec=Config)
@@ -88,3 +94,13 @@ class TestClad:
         assert str(exc_info.value) == "Serve error"
         mock_load_config.assert_called_once()
         mock_setup_logging.assert_called_once()
+
+
+def test_daemonize_config_argument(
+    tmp_path, mock_setup_logging, mock_serve, mock_load_config
+):
+    config_file = tmp_path / "config.toml"
+    with patch("sys.argv", ["clad", "--config", str(config_file)]):
+        assert daemonize() == 0
+
+    mock_load_config.assert_called_once_with(config_file)
@@ -3,12 +3,14 @@ from unittest.mock import patch
 import pytest
 from dasbus.server.template import InterfaceTemplate
 
+from command_line_assistant.config.schemas.backend import REQUEST_TEMPLATE_VARIABLES
 from command_line_assistant.constants import VERSION
 from command_line_assistant.daemon.database.manager import DatabaseManager
 from command_line_assistant.daemon.database.repository.chat import ChatRepository
 from command_line_assistant.dbus.exceptions import ChatNotFoundError
 from command_line_assistant.dbus.interfaces.chat import (
     ChatInterface,
+    InferencePayload,
 )
 from command_line_assistant.dbus.structures.chat import (
     AttachmentInput,
@@ -16,6 +18,7 @@ from command_line_assistant.dbus.structures.chat import (
     Question,
     Response,
     StdinInput,
+    SystemInfo,
 )
 
 
@@ -72,6 +75,74 @@ def test_chat_interface_ask_question(chat_interface, mock_config, mock_authoriza
         assert reconstructed.message == expected_response
 
 
+def test_chat_interface_ask_question_without_system_info(
+    chat_interface, mock_config, mock_authorization
+):
+    mock_config.privacy.send_system_info = False
+    with patch(
+        "command_line_assistant.dbus.interfaces.chat.submit",
+        return_value="test response",
+    ) as mock_submit:
+        uid = "2345f9e6-dfea-11ef-9ae9-52b437312584"
+        message_input = Question("test", StdinInput(), AttachmentInput())
+        chat_interface.AskQuestion(uid, message_input.structure())
+
+        payload = mock_submit.call_args[0][0]
+        assert "systeminfo" not in payload["context"]
+        assert payload["context"]["cla"] == {"version": VERSION}
+
+
+@pytest.mark.parametrize(
+    ("send_system_info", "expected"),
+    (
+        (True, {"os": "RHEL", "version": "10", "arch": "x86_64", "id": "abc"}),
+        (False, None),
+    ),
+)
+def test_inference_payload_system_info(send_system_info, expected):
+    question = Question(
+        "test",
+        StdinInput(),
+        AttachmentInput(),
+        systeminfo=SystemInfo(os="RHEL", version="10", arch="x86_64", id="abc"),
+    )
+    result = InferencePayload(question, send_system_info).to_dict()
+
+    assert result["context"].get("systeminfo") == expected
+
+
+@pytest.mark.parametrize(
+    ("send_system_info", "expected_os"),
+    (
+        (True, "RHEL"),
+        (False, ""),
+    ),
+)
+def test_inference_payload_request_template(send_system_info, expected_os):
+    question = Question(
+        "test",
+        StdinInput(stdin="stdin"),
+        AttachmentInput(),
+        systeminfo=SystemInfo(os="RHEL", version="10", arch="x86_64", id="abc"),
+    )
+    template = '{"prompt": "${question}", "input": "${stdin}", "os": "${system_info.os}", "version": "${cla.version}"}'
+    result = InferencePayload(question, send_system_info, template).to_dict()
+
+    assert result == {
+        "prompt": "test",
+        "input": "stdin",
+        "os": expected_os,
+        "version": VERSION,
+    }
+
+
+def test_inference_payload_template_variables():
+    question = Question("test", StdinInput(), AttachmentInput())
+    variables = InferencePayload(question).to_template_variables()
+
+    assert tuple(variables) == REQUEST_TEMPLATE_VARIABLES
+
+
 def test_get_all_chat_from_user(chat_interface, mock_repository, mock_authorization):
     uid = "2345f9e6-dfea-11ef-9ae9-52b437312584"
     mock_repository.insert({"name": "test", "description": "test", "user_id": uid})
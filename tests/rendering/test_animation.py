@@ -0,0 +1,58 @@
+import io
+from unittest.mock import patch
+
+import pytest
+
+from command_line_assistant.rendering import animation
+
+
+class FakeTTY(io.StringIO):
+    def isatty(self):
+        return True
+
+
+class ClosedStream(io.StringIO):
+    def isatty(self):
+        raise ValueError("I/O operation on closed file.")
+
+
+@pytest.mark.parametrize(
+    ("stream", "expected"),
+    (
+        (FakeTTY(), True),
+        (io.StringIO(), False),
+        (ClosedStream(), False),
+        (object(), False),
+    ),
+)
+def test_should_animate(stream, expected):
+    assert animation.should_animate(stream) == expected
+
+
+def test_spinner_without_tty():
+    stderr = io.StringIO()
+    with patch("sys.stderr", stderr):
+        with animation.Spinner(message="Asking RHEL Lightspeed", plain=True):
+            pass
+
+    assert stderr.getvalue() == ""
+
+
+def test_spinner_disabled_with_tty():
+    stderr = FakeTTY()
+    with patch("sys.stderr", stderr):
+        with animation.Spinner(
+            message="Asking RHEL Lightspeed", plain=True, enabled=False
+        ):
+            pass
+
+    assert stderr.getvalue() == ""
+
+
+def test_spinner_with_tty():
+    stderr = FakeTTY()
+    with patch("sys.stderr", stderr):
+        with animation.Spinner(message="Asking RHEL Lightspeed", plain=True):
+            pass
+
+    assert stderr.getvalue() == "⁺₊+ Asking RHEL Lightspeed...\n"
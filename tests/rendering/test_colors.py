@@ -7,7 +7,13 @@ from unittest.mock import patch
 
 import pytest
 
-from command_line_assistant.rendering.colors import Color, Style, colorize, stylize
+from command_line_assistant.rendering.colors import (
+    Color,
+    Style,
+    colorize,
+    should_colorize,
+    stylize,
+)
 
 
 class TestColorizeFunction:
@@ -296,3 +302,31 @@ class TestIntegrationScenarios:
 
             assert colored == text
             assert styled == text
+
+
+class FakeTTY:
+    def __init__(self, tty: bool):
+        self._tty = tty
+
+    def isatty(self):
+        return self._tty
+
+
+@pytest.mark.parametrize(
+    ("env", "tty", "no_color", "expected"),
+    (
+        ({}, True, False, True),
+        ({}, False, False, False),
+        ({}, True, True, False),
+        ({"NO_COLOR": "1"}, True, False, False),
+        ({"NO_COLOR": ""}, True, False, True),
+    ),
+)
+def test_should_colorize(env, tty, no_color, expected):
+    with patch.dict(os.environ, env, clear=True):
+        assert should_colorize(FakeTTY(tty), no_color) == expected
+
+
+def test_should_colorize_without_isatty():
+    with patch.dict(os.environ, {}, clear=True):
+        assert not should_colorize(object())  # type: ignore[arg-type]
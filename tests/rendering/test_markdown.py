@@ -5,6 +5,7 @@ from command_line_assistant.rendering.markdown import (
     ANSIExtension,
     ANSIMarkdown,
     ANSIRenderer,
+    extract_last_code_block,
     markdown_to_ansi,
 )
 
@@ -929,3 +930,21 @@ Final paragraph."""
         )  # Table (may not render with borders in all cases)
         assert "\033[90m" in result  # Horizontal rule
         assert "Final paragraph" in result
+
+
+@pytest.mark.parametrize(
+    ("text", "expected"),
+    (
+        ("```bash\nls -la\n```", "ls -la"),
+        ("Run:\n```\nls -la\ncd /tmp\n```\nDone.", "ls -la\ncd /tmp"),
+        (
+            "```bash\nls\n```\nThen:\n  ```python\nprint('hi')\n  ```\n",
+            "print('hi')",
+        ),
+        ("Use `ls -la` to list the files.", None),
+        ("```bash\nls -la\n", None),
+        ("", None),
+    ),
+)
+def test_extract_last_code_block(text, expected):
+    assert extract_last_code_block(text) == expected
@@ -1,3 +1,5 @@
+import sys
+
 import pytest
 
 from command_line_assistant.rendering import renderers
@@ -43,3 +45,25 @@ from command_line_assistant.rendering import renderers
 )
 def test_human_readable_size(size, expected):
     assert renderers.human_readable_size(size) == expected
+
+
+def test_renderer_custom_stream(capsys):
+    renderer = renderers.Renderer(plain=True, stream=sys.stderr)
+    renderer.normal("normal message")
+    renderer.warning("warning message")
+
+    captured = capsys.readouterr()
+    assert not captured.out
+    assert "normal message" in captured.err
+    assert "warning message" in captured.err
+
+
+@pytest.mark.parametrize("quiet", (False, True))
+def test_renderer_status(capsys, quiet):
+    renderer = renderers.Renderer(plain=True, stream=sys.stderr, quiet=quiet)
+    renderer.status("status message")
+    renderer.normal("normal message")
+
+    captured = capsys.readouterr()
+    assert "normal message" in captured.err
+    assert ("status message" in captured.err) is not quiet
@@ -1,12 +1,27 @@
+import os
 from unittest.mock import Mock, patch
 
 import pytest
 from dasbus.error import DBusError
 
-from command_line_assistant.client import main
+from command_line_assistant.client import main, register_subcommands
+from command_line_assistant.commands.cli import add_default_command
 from command_line_assistant.constants import VERSION
 
 
+@pytest.fixture(autouse=True)
+def mock_should_colorize():
+    """Pretend the output is a terminal and keep the NO_COLOR changes made by
+    main from leaking into other tests."""
+    with (
+        patch.dict(os.environ),
+        patch(
+            "command_line_assistant.client.should_colorize", return_value=True
+        ) as mock,
+    ):
+        yield mock
+
+
 def test_initialize_with_no_args(capsys):
     """Test initialize with no arguments - should print help and return 1"""
     with (
@@ -42,12 +57,67 @@ def test_initialize_with_query_command(argv, stdin):
         patch("argparse.ArgumentParser.parse_args") as mock_parse,
     ):
         mock_parse.return_value.func = mock_command
+        mock_parse.return_value.log_level = None
         result = main()
 
         assert result == 0
         mock_command.assert_called_once()
 
 
+@pytest.mark.parametrize(
+    ("argv", "expected"),
+    (
+        (["c", "chat", "--", "-rf", "means", "what"], "-rf means what"),
+        (["c", "--", "what", "does", "-rf", "do", "in", "rm"], "what does -rf do in rm"),
+        (["c", "--", "--help", "me"], "--help me"),
+    ),
+)
+def test_parse_query_with_leading_dash(argv, expected):
+    """Words starting with a dash are kept in the question after the sentinel."""
+    parsed_args = register_subcommands().parse_args(add_default_command(None, argv))
+
+    assert parsed_args.query_string == expected
+
+
+@pytest.mark.parametrize(
+    ("log_level", "debug", "expected"),
+    (
+        (None, False, None),
+        (None, True, "DEBUG"),
+        ("INFO", False, "INFO"),
+        ("WARNING", True, "WARNING"),
+    ),
+)
+def test_initialize_log_level(log_level, debug, expected):
+    """The --log-level flag wins over --debug when setting up the logging."""
+    with (
+        patch("sys.argv", ["c", "chat", "test"]),
+        patch("command_line_assistant.commands.cli.register_all_commands"),
+        patch("command_line_assistant.client.read_stdin", lambda: None),
+        patch("argparse.ArgumentParser.parse_args") as mock_parse,
+        patch("command_line_assistant.client.setup_client_logging") as mock_logging,
+    ):
+        mock_parse.return_value.func = Mock(return_value=0)
+        mock_parse.return_value.log_level = log_level
+        mock_parse.return_value.debug = debug
+
+        assert main() == 0
+
+    if expected:
+        mock_logging.assert_called_once_with(expected)
+    else:
+        mock_logging.assert_not_called()
+
+
+def test_parse_log_level():
+    """The level is case insensitive and kept as a global flag."""
+    argv = ["c", "--log-level", "info", "what is this?"]
+    parsed_args = register_subcommands().parse_args(add_default_command(None, argv))
+
+    assert parsed_args.log_level == "INFO"
+    assert parsed_args.query_string == "what is this?"
+
+
 def test_initialize_with_history_command():
     """Test initialize with history command"""
     mock_command = Mock(return_value=0)
@@ -59,6 +129,7 @@ def test_initialize_with_history_command():
         patch("argparse.ArgumentParser.parse_args") as mock_parse,
     ):
         mock_parse.return_value.func = mock_command
+        mock_parse.return_value.log_level = None
         result = main()
 
         assert result == 0
@@ -76,6 +147,7 @@ def test_initialize_with_shell_command():
         patch("argparse.ArgumentParser.parse_args") as mock_parse,
     ):
         mock_parse.return_value.func = mock_command
+        mock_parse.return_value.log_level = None
         result = main()
 
         assert result == 0
@@ -117,13 +189,25 @@ def test_initialize_bad_stdin(capsys, disable_stream_flush):
     assert "🙁 \x1b[31mBinary input are not supported.\x1b[0m\n" in captured.out
 
 
+def test_initialize_timeout(capsys, disable_stream_flush):
+    with patch("command_line_assistant.client.read_stdin") as mock_stdin:
+        mock_stdin.side_effect = TimeoutError("Didn't receive an answer.")
+        result = main()
+
+    captured = capsys.readouterr()
+    assert result == 69  # os.EX_UNAVAILABLE
+    assert "Didn't receive an answer." in captured.out
+
+
 def test_initialize_keyboard_interrupt(capsys, disable_stream_flush):
     with patch("command_line_assistant.client.read_stdin") as mock_stdin:
         mock_stdin.side_effect = KeyboardInterrupt("Interrupted")
-        main()
+        result = main()
 
     captured = capsys.readouterr()
-    assert "🙁 \x1b[31mKeyboard interrupt detected. Exiting...\x1b[0m\n" in captured.out
+    assert result == 130
+    assert captured.out.count("Cancelled.") == 1
+    assert "🙁 \x1b[31mCancelled.\x1b[0m\n" in captured.out
 
 
 @pytest.mark.parametrize(
@@ -190,8 +274,36 @@ def test_command_exception_handling():
         patch("argparse.ArgumentParser.parse_args") as mock_parse,
     ):
         mock_parse.return_value.func = mock_command
+        mock_parse.return_value.log_level = None
         mock_parse.return_value.plain = False
         result = main()
 
         assert result == 65  # os.EX_DATAERR
         mock_command.assert_called_once()
+
+
+@pytest.mark.parametrize(
+    ("argv", "colorize", "no_color", "expected"),
+    (
+        (["c", "--no-color", "history"], False, True, "1"),
+        (["c", "history"], False, False, "1"),
+        (["c", "history"], True, False, None),
+    ),
+)
+def test_no_color(argv, colorize, no_color, expected, mock_should_colorize):
+    mock_command = Mock(return_value=0)
+    mock_should_colorize.return_value = colorize
+    os.environ.pop("NO_COLOR", None)
+
+    with (
+        patch("sys.argv", argv),
+        patch("command_line_assistant.commands.cli.register_all_commands"),
+        patch("command_line_assistant.client.read_stdin", lambda: None),
+        patch("argparse.ArgumentParser.parse_args") as mock_parse,
+    ):
+        mock_parse.return_value.func = mock_command
+        mock_parse.return_value.log_level = None
+        assert main() == 0
+
+    assert mock_should_colorize.call_args[0][1] == no_color
+    assert os.environ.get("NO_COLOR") == expected
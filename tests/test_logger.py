@@ -1,5 +1,6 @@
 import json
 import logging
+import sys
 from unittest.mock import patch
 
 import pytest
@@ -8,6 +9,7 @@ from command_line_assistant.logger import (
     EXTRAS_TO_SKIP,
     AuditFilter,
     AuditFormatter,
+    JSONFormatter,
     NonAuditFilter,
     setup_daemon_logging,
 )
@@ -120,6 +122,64 @@ def test_setup_logging(mock_dict_config, mock_config):
     mock_dict_config.assert_called_once()
 
 
+@pytest.mark.parametrize(
+    ("log_format", "handler"), (("text", "systemd"), ("json", "systemd_json"))
+)
+@patch("logging.config.dictConfig")
+def test_setup_logging_format(mock_dict_config, log_format, handler, mock_config):
+    mock_config.logging.format = log_format
+    setup_daemon_logging(mock_config)
+
+    configuration = mock_dict_config.call_args[0][0]
+    assert configuration["loggers"]["root"]["handlers"][0] == handler
+
+
+def test_setup_logging_json_format(mock_config):
+    mock_config.logging.format = "json"
+    mock_config.logging.level = "INFO"
+    setup_daemon_logging(mock_config)
+
+    handlers = logging.getLogger().handlers
+    assert any(isinstance(handler.formatter, JSONFormatter) for handler in handlers)
+
+
+def test_json_formatter_format():
+    record = logging.LogRecord(
+        name="test",
+        level=logging.WARNING,
+        pathname="test.py",
+        lineno=1,
+        msg="Test %s",
+        args=("message",),
+        exc_info=None,
+    )
+
+    data = json.loads(JSONFormatter().format(record))
+    assert data["message"] == "Test message"
+    assert data["level"] == "WARNING"
+    assert data["logger"] == "test"
+    assert data["code"] == {"file": "test.py", "line": 1, "function": None}
+    assert "exception" not in data
+
+
+def test_json_formatter_with_exception():
+    try:
+        raise ValueError("boom")
+    except ValueError:
+        record = logging.LogRecord(
+            name="test",
+            level=logging.ERROR,
+            pathname="test.py",
+            lineno=1,
+            msg="Failed",
+            args=(),
+            exc_info=sys.exc_info(),
+        )
+
+    data = json.loads(JSONFormatter().format(record))
+    assert "ValueError: boom" in data["exception"]
+
+
 def test_audit_formatter_user_specific_logging():
     """Test user-specific logging configuration."""
     # Configure mock for user-specific settings
@@ -0,0 +1,140 @@
+import subprocess
+from unittest import mock
+
+import pytest
+
+from command_line_assistant.utils import clipboard
+
+
+@pytest.mark.parametrize(
+    ("env", "available", "expected"),
+    (
+        ({"WAYLAND_DISPLAY": "wayland-0"}, ("wl-paste",), ["wl-paste", "--no-newline"]),
+        (
+            {"DISPLAY": ":0"},
+            ("xclip", "xsel"),
+            ["xclip", "-selection", "clipboard", "-o"],
+        ),
+        ({"DISPLAY": ":0"}, ("xsel",), ["xsel", "--clipboard", "--output"]),
+        # XWayland sessions fall back to the X11 tools.
+        (
+            {"WAYLAND_DISPLAY": "wayland-0", "DISPLAY": ":0"},
+            ("xclip",),
+            ["xclip", "-selection", "clipboard", "-o"],
+        ),
+        ({"WAYLAND_DISPLAY": "wayland-0"}, ("xclip",), None),
+        ({}, ("wl-paste", "xclip", "xsel"), None),
+    ),
+)
+def test_get_paste_command(env, available, expected, monkeypatch):
+    monkeypatch.delenv("WAYLAND_DISPLAY", raising=False)
+    monkeypatch.delenv("DISPLAY", raising=False)
+    for key, value in env.items():
+        monkeypatch.setenv(key, value)
+    monkeypatch.setattr(
+        clipboard.shutil,
+        "which",
+        lambda name: f"/usr/bin/{name}" if name in available else None,
+    )
+
+    assert clipboard.get_paste_command() == expected
+
+
+def test_read_clipboard(monkeypatch):
+    monkeypatch.setattr(clipboard, "get_paste_command", lambda: ["wl-paste"])
+    with mock.patch.object(clipboard.subprocess, "run") as mock_run:
+        mock_run.return_value.stdout = "how to list files?"
+        assert clipboard.read_clipboard() == "how to list files?"
+
+    mock_run.assert_called_once_with(
+        ["wl-paste"],
+        capture_output=True,
+        text=True,
+        check=True,
+        timeout=clipboard.PASTE_TIMEOUT,
+    )
+
+
+def test_read_clipboard_no_tool(monkeypatch):
+    monkeypatch.setattr(clipboard, "get_paste_command", lambda: None)
+
+    with pytest.raises(ValueError, match="Couldn't find a tool to read the clipboard"):
+        clipboard.read_clipboard()
+
+
+@pytest.mark.parametrize(
+    ("exception",),
+    (
+        (subprocess.CalledProcessError(1, "xclip"),),
+        (subprocess.TimeoutExpired("xclip", 5),),
+        (FileNotFoundError(),),
+    ),
+)
+def test_read_clipboard_failure(exception, monkeypatch):
+    monkeypatch.setattr(clipboard, "get_paste_command", lambda: ["xclip"])
+    monkeypatch.setattr(clipboard.subprocess, "run", mock.Mock(side_effect=exception))
+
+    with pytest.raises(ValueError, match="Couldn't read the clipboard using 'xclip'"):
+        clipboard.read_clipboard()
+
+
+@pytest.mark.parametrize(
+    ("env", "available", "expected"),
+    (
+        ({"WAYLAND_DISPLAY": "wayland-0"}, ("wl-copy",), ["wl-copy"]),
+        ({"DISPLAY": ":0"}, ("xclip", "xsel"), ["xclip", "-selection", "clipboard"]),
+        ({"DISPLAY": ":0"}, ("xsel",), ["xsel", "--clipboard", "--input"]),
+        ({"WAYLAND_DISPLAY": "wayland-0"}, ("xclip",), None),
+    ),
+)
+def test_get_copy_command(env, available, expected, monkeypatch):
+    monkeypatch.delenv("WAYLAND_DISPLAY", raising=False)
+    monkeypatch.delenv("DISPLAY", raising=False)
+    for key, value in env.items():
+        monkeypatch.setenv(key, value)
+    monkeypatch.setattr(
+        clipboard.shutil,
+        "which",
+        lambda name: f"/usr/bin/{name}" if name in available else None,
+    )
+
+    assert clipboard.get_copy_command() == expected
+
+
+def test_write_clipboard(monkeypatch):
+    monkeypatch.setattr(clipboard, "get_copy_command", lambda: ["wl-copy"])
+    with mock.patch.object(clipboard.subprocess, "run") as mock_run:
+        clipboard.write_clipboard("ls -la")
+
+    mock_run.assert_called_once_with(
+        ["wl-copy"],
+        input="ls -la",
+        stdout=subprocess.DEVNULL,
+        stderr=subprocess.DEVNULL,
+        text=True,
+        check=True,
+        timeout=clipboard.PASTE_TIMEOUT,
+    )
+
+
+def test_write_clipboard_no_tool(monkeypatch):
+    monkeypatch.setattr(clipboard, "get_copy_command", lambda: None)
+
+    with pytest.raises(
+        ValueError, match="Couldn't find a tool to write to the clipboard"
+    ):
+        clipboard.write_clipboard("ls -la")
+
+
+def test_write_clipboard_failure(monkeypatch):
+    monkeypatch.setattr(clipboard, "get_copy_command", lambda: ["xsel"])
+    monkeypatch.setattr(
+        clipboard.subprocess,
+        "run",
+        mock.Mock(side_effect=subprocess.CalledProcessError(1, "xsel")),
+    )
+
+    with pytest.raises(
+        ValueError, match="Couldn't write to the clipboard using 'xsel'"
+    ):
+        clipboard.write_clipboard("ls -la")
@@ -0,0 +1,44 @@
+import pytest
+
+from command_line_assistant.utils.template import (
+    render_json_template,
+    validate_json_template,
+)
+
+
+@pytest.mark.parametrize(
+    ("template",),
+    (
+        ('{"query": "${question}"}',),
+        ('{"query": "${question}", "os": "${system_info.os}"}',),
+        ('{"static": true}',),
+    ),
+)
+def test_validate_json_template(template):
+    validate_json_template(template, ("question", "system_info.os"))
+
+
+@pytest.mark.parametrize(
+    ("template", "match"),
+    (
+        ('{"query": "${question}"', "is not valid JSON"),
+        ('["${question}"]', "must be a JSON object"),
+        ('{"query": "${unknown}"}', "unknown placeholders: unknown"),
+    ),
+)
+def test_validate_json_template_invalid(template, match):
+    with pytest.raises(ValueError, match=match):
+        validate_json_template(template, ("question",))
+
+
+def test_render_json_template():
+    template = '{"query": "Q: ${question}", "meta": {"os": ["${system_info.os}", 1]}, "stream": false}'
+    variables = {"question": 'say "hi"\n', "system_info.os": "RHEL"}
+
+    result = render_json_template(template, variables)
+
+    assert result == {
+        "query": 'Q: say "hi"\n',
+        "meta": {"os": ["RHEL", 1]},
+        "stream": False,
+    }